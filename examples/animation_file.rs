@@ -0,0 +1,41 @@
+use freya::prelude::*;
+use freya_motion::use_animation_file;
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    // Edit `examples/motion/card.ron` while the example is running and click
+    // the card to replay the animation with the new timings.
+    let (animation, error) = use_animation_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/motion/card.ron"
+    ));
+
+    let [offset, opacity] = [
+        animation.get::<f32>("offset"),
+        animation.get::<f32>("opacity"),
+    ];
+
+    use_hook(move || {
+        animation.play();
+    });
+
+    rsx! {
+        rect {
+            background: "red",
+            width: "128",
+            height: "128",
+            corner_radius: "12",
+            opacity: "{opacity}",
+            margin: "0 0 0 {offset}",
+            onclick: move |_| {
+                animation.play();
+            },
+        }
+        if let Some(error) = &*error.read() {
+            label { color: "red", "{error}" }
+        }
+    }
+}
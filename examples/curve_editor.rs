@@ -0,0 +1,20 @@
+use freya::prelude::*;
+use freya_motion::{Curve, CurveEditor};
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    let mut curve = use_signal(|| Curve::EASE);
+
+    rsx! {
+        rect {
+            padding: "24",
+            CurveEditor {
+                value: curve(),
+                onchange: move |value| curve.set(value),
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use freya::prelude::*;
 use freya_motion::{use_transition, Curve};
+use std::time::Duration;
 
 fn main() {
     launch(app);
@@ -7,8 +8,20 @@ fn main() {
 
 fn app() -> Element {
     let animation = use_transition(|context| {
-        context.add_tween_delayed("offset", 0.0, Curve::EASE_IN_OUT_CIRC, 500, 500);
-        context.add_tween_delayed("opacity", 1.0, Curve::FAST_OUT_SLOW_IN, 1000, 1000);
+        context.add_tween_delayed(
+            "offset",
+            0.0,
+            Curve::EASE_IN_OUT_CIRC,
+            Duration::from_millis(500),
+            Duration::from_millis(500),
+        );
+        context.add_tween_delayed(
+            "opacity",
+            1.0,
+            Curve::FAST_OUT_SLOW_IN,
+            Duration::from_millis(1000),
+            Duration::from_millis(1000),
+        );
     });
 
     let [offset, opacity] = [
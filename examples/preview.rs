@@ -0,0 +1,20 @@
+use freya_motion::{preview, preview::CurvePreview, Curve};
+
+fn main() {
+    std::fs::write(
+        "ease_out_back.png",
+        CurvePreview::new(&Curve::EASE_OUT_BACK)
+            .velocity(true)
+            .to_png()
+            .expect("failed to render preview"),
+    )
+    .unwrap();
+
+    std::fs::write(
+        "presets.png",
+        preview::contact_sheet(8, (160, 160)).expect("failed to render contact sheet"),
+    )
+    .unwrap();
+
+    std::fs::write("presets.svg", preview::contact_sheet_svg(8, (160, 160))).unwrap();
+}
@@ -0,0 +1,72 @@
+use freya::prelude::*;
+use freya_motion::{use_transition, Curve};
+use std::time::Duration;
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    let animation = use_transition(|context| {
+        context.add_tween("offset", 0.0, Curve::LINEAR, Duration::ZERO);
+        context.add_tween("opacity", 1.0, Curve::LINEAR, Duration::ZERO);
+    });
+
+    let [offset, opacity] = [
+        animation.get::<f32>("offset"),
+        animation.get::<f32>("opacity"),
+    ];
+
+    let onclick = move |_| {
+        animation.script(|animation| async move {
+            animation
+                .to(
+                    "offset",
+                    256.0,
+                    Curve::EASE_IN_OUT_CIRC,
+                    Duration::from_millis(500),
+                )
+                .await;
+            animation.wait(Duration::from_millis(200)).await;
+
+            // Both values are animated at the same time
+            let fade = animation.to(
+                "opacity",
+                0.2,
+                Curve::FAST_OUT_SLOW_IN,
+                Duration::from_millis(600),
+            );
+
+            animation
+                .to(
+                    "offset",
+                    0.0,
+                    Curve::EASE_IN_OUT_CIRC,
+                    Duration::from_millis(500),
+                )
+                .await;
+            fade.await;
+
+            animation
+                .to(
+                    "opacity",
+                    1.0,
+                    Curve::FAST_OUT_SLOW_IN,
+                    Duration::from_millis(300),
+                )
+                .await;
+        });
+    };
+
+    rsx! {
+        rect {
+            background: "red",
+            width: "128",
+            height: "128",
+            corner_radius: "12",
+            opacity: "{opacity}",
+            margin: "0 0 0 {offset}",
+            onclick,
+        }
+    }
+}
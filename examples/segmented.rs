@@ -1,5 +1,6 @@
 use freya::prelude::*;
 use freya_motion::{use_segmented_animation, Curve};
+use std::time::Duration;
 
 fn main() {
     launch(app);
@@ -10,10 +11,25 @@ fn app() -> Element {
         context.add_tween("offset", 0.0);
         context.add_tween("opacity", 0.0);
 
-        context.add_segment("opacity", 0.0, Curve::None, 1000);
-        context.add_segment("opacity", 1.0, Curve::FAST_OUT_SLOW_IN, 1000);
-        context.add_segment("offset", 256.0, Curve::EASE_IN_OUT_CIRC, 500);
-        context.add_segment("opacity", 0.0, Curve::FAST_OUT_SLOW_IN, 1000);
+        context.add_segment("opacity", 0.0, Curve::None, Duration::from_millis(1000));
+        context.add_segment(
+            "opacity",
+            1.0,
+            Curve::FAST_OUT_SLOW_IN,
+            Duration::from_millis(1000),
+        );
+        context.add_segment(
+            "offset",
+            256.0,
+            Curve::EASE_IN_OUT_CIRC,
+            Duration::from_millis(500),
+        );
+        context.add_segment(
+            "opacity",
+            0.0,
+            Curve::FAST_OUT_SLOW_IN,
+            Duration::from_millis(1000),
+        );
     });
 
     let [offset, opacity] = [
@@ -0,0 +1,54 @@
+use freya::prelude::*;
+use freya_motion::{use_motion_theme, use_segmented_animation, MotionTheme};
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    let mut theme = use_motion_theme(MotionTheme::material3);
+    let mut is_fluent = use_signal(|| false);
+
+    rsx! {
+        Button {
+            onclick: move |_| {
+                is_fluent.toggle();
+
+                theme.set(if is_fluent() { MotionTheme::fluent() } else { MotionTheme::material3() });
+            },
+            label { if is_fluent() { "Fluent" } else { "Material 3" } }
+        }
+        Card {}
+    }
+}
+
+#[component]
+fn Card() -> Element {
+    let animation = use_segmented_animation(|context| {
+        context.add_tween("offset", 0.0);
+        context.add_tween("opacity", 0.0);
+
+        context.add_segment("opacity", 1.0, "standard-decelerate", "medium2");
+        context.add_segment("offset", 256.0, "emphasized", "long2");
+        context.add_segment("opacity", 0.0, "standard-accelerate", "short4");
+    });
+
+    let [offset, opacity] = [
+        animation.get::<f32>("offset"),
+        animation.get::<f32>("opacity"),
+    ];
+
+    rsx! {
+        rect {
+            background: "red",
+            width: "128",
+            height: "128",
+            corner_radius: "12",
+            opacity: "{opacity}",
+            margin: "0 0 0 {offset}",
+            onclick: move |_| {
+                animation.play();
+            },
+        }
+    }
+}
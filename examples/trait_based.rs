@@ -1,5 +1,6 @@
 use freya::prelude::*;
 use freya_motion::{use_trait_animation, Curve, PathAnimation};
+use std::time::Duration;
 
 fn main() {
     launch(app);
@@ -20,13 +21,23 @@ fn app() -> Element {
         animation.play(
             "opacity",
             PathAnimation::default()
-                .insert(1.0, Curve::FAST_OUT_SLOW_IN, 1000)
-                .insert_delayed(0.0, Curve::FAST_OUT_SLOW_IN, 1000, 500),
+                .insert(1.0, Curve::FAST_OUT_SLOW_IN, Duration::from_millis(1000))
+                .insert_delayed(
+                    0.0,
+                    Curve::FAST_OUT_SLOW_IN,
+                    Duration::from_millis(1000),
+                    Duration::from_millis(500),
+                ),
         );
 
         animation.play(
             "offset",
-            PathAnimation::default().insert_delayed(256.0, Curve::EASE_IN_OUT_CIRC, 500, 1000),
+            PathAnimation::default().insert_delayed(
+                256.0,
+                Curve::EASE_IN_OUT_CIRC,
+                Duration::from_millis(500),
+                Duration::from_millis(1000),
+            ),
         );
     });
 
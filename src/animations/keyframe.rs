@@ -1,17 +1,29 @@
 use super::Animation;
-use crate::{Curve, Tween, Value};
+use crate::{AnimationDirection, Curve, IterationCount, Lerp, Repeat, Tween, Value};
+use std::time::Duration;
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Frame {
     position: f32,
     value: Value,
     curve: Option<Curve>,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyFrameAnimation {
     frames: Vec<Frame>,
-    current_frame: Option<usize>,
-    duration: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::millis"))]
+    duration: Duration,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Repeat::is_once")
+    )]
+    repeat: Repeat,
+    /// The value of the tween when the animation started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    origin: Option<Value>,
 }
 
 impl KeyFrameAnimation {
@@ -30,83 +42,108 @@ impl KeyFrameAnimation {
         self
     }
 
-    pub fn duration(mut self, duration: u64) -> Self {
+    pub fn duration(mut self, duration: Duration) -> Self {
         self.duration = duration;
 
         self
     }
 
-    fn update_current_keyframe(&mut self, time: f32) {
-        // Common cases, reversing/wrapping
-        if !self.frames.is_empty() && time == 0.0 {
-            self.current_frame = Some(0);
-            return;
-        }
-        if !self.frames.is_empty() && time == self.get_duration() as f32 {
-            self.current_frame = Some(self.frames.len() - 1);
-            return;
-        }
+    /// Plays the keyframes `count` times, like the CSS
+    /// `animation-iteration-count`.
+    pub fn repeat(mut self, count: impl Into<IterationCount>) -> Self {
+        self.repeat.iterations = count.into();
 
-        if let Some(k) = self.current_frame {
-            if self.frames.len() <= k {
-                self.current_frame = None;
-            }
+        self
+    }
 
-            if self.frames[k].position > time {
-                for i in (0..self.current_frame.unwrap_or(0)).rev() {
-                    if self.frames[i].position <= time {
-                        self.current_frame = Some(i);
-                        return;
-                    }
+    /// Plays every other iteration backwards, going back and forth.
+    pub const fn alternate(mut self) -> Self {
+        self.repeat.direction = AnimationDirection::Alternate;
 
-                    self.current_frame = None;
-                }
-            } else {
-                let copy = self.current_frame;
-                self.current_frame = None;
-
-                for i in copy.unwrap_or(0)..self.frames.len() {
-                    if self.frames[i].position > time {
-                        break;
-                    } else {
-                        self.current_frame = Some(i)
-                    }
+        self
+    }
+
+    pub const fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.repeat.direction = direction;
+
+        self
+    }
+
+    /// Waits `delay` between two iterations.
+    pub const fn repeat_delay(mut self, delay: Duration) -> Self {
+        self.repeat.delay = delay;
+
+        self
+    }
+
+    /// Starts at `iteration`, `0.5` starting halfway through the first one.
+    pub const fn iteration_start(mut self, iteration: f32) -> Self {
+        self.repeat.iteration_start = iteration;
+
+        self
+    }
+
+    /// The value of the keyframes at `time`, from `0.0` to `1.0`. Before the
+    /// first frame, the value moves linearly from `origin`.
+    fn value_at(&self, origin: &Value, time: f32) -> Option<Value> {
+        let next = self.frames.iter().position(|frame| frame.position > time);
+        let (from, to, curve) = match next {
+            Some(0) => {
+                let to = &self.frames[0];
+
+                ((0.0, origin), to, Curve::LINEAR)
+            }
+            Some(next) => {
+                let from = &self.frames[next - 1];
+
+                match &from.curve {
+                    Some(curve) => (
+                        (from.position, &from.value),
+                        &self.frames[next],
+                        curve.clone(),
+                    ),
+                    // Held until the next frame
+                    None => return Some(from.value.clone()),
                 }
             }
-        } else if !self.frames.is_empty() {
-            self.current_frame = Some(0);
-            self.update_current_keyframe(time);
-        }
+            None => return self.frames.last().map(|frame| frame.value.clone()),
+        };
+        let length = to.position - from.0;
+
+        Some(from.1.lerp(
+            &to.value,
+            curve.transform_for_duration((time - from.0) / length, self.duration.mul_f32(length)),
+        ))
     }
 }
 
 impl Animation for KeyFrameAnimation {
     fn init(&self, _: &mut Tween) {}
 
-    fn advance(&mut self, tween: &mut Tween, index: u128) {
-        let time = index as f32 / self.duration as f32;
-
-        self.update_current_keyframe(time);
-
-        if let Some((frame, curve)) = self.current_frame.map(|frame| {
-            (
-                &self.frames[frame],
-                self.frames[frame].curve.clone().unwrap_or(Curve::None),
-            )
-        }) {
-            if tween.curve != curve {
-                tween.curve = curve.clone();
+    #[allow(clippy::cast_possible_truncation)]
+    fn advance(&mut self, tween: &mut Tween, elapsed: Duration) {
+        let origin = self
+            .origin
+            .get_or_insert_with(|| tween.value.clone())
+            .clone();
+        let progress = self.repeat.position(elapsed, self.duration).progress;
+
+        if let Some(value) = self.value_at(&origin, progress as f32) {
+            if tween.value != value {
+                tween.value = value;
             }
+        }
+    }
 
-            if tween.destination != frame.value {
-                tween.to(frame.value.clone());
-            }
+    fn get_duration(&self) -> Option<Duration> {
+        self.repeat.total_duration(self.duration)
+    }
 
-            tween.advance(index as f32);
-        }
+    fn get_iteration(&self, elapsed: Duration) -> u32 {
+        self.repeat.position(elapsed, self.duration).iteration
     }
 
-    fn get_duration(&self) -> u64 {
-        self.duration
+    fn get_progress(&self, elapsed: Duration) -> f32 {
+        self.repeat.progress(elapsed, self.duration)
     }
 }
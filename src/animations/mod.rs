@@ -1,11 +1,34 @@
 pub use self::{keyframe::KeyFrameAnimation, path::PathAnimation};
 use crate::Tween;
+use std::time::Duration;
 
 mod keyframe;
 mod path;
 
 pub trait Animation {
     fn init(&self, tween: &mut Tween);
-    fn advance(&mut self, tween: &mut Tween, index: u128);
-    fn get_duration(&self) -> u64;
+    /// Moves the tween to where it is `elapsed` after the animation started.
+    fn advance(&mut self, tween: &mut Tween, elapsed: Duration);
+    /// How long the animation lasts, all its iterations included, `None` if it
+    /// repeats forever.
+    ///
+    /// This used to return a [`Duration`]: animations which end return it
+    /// wrapped in `Some`.
+    fn get_duration(&self) -> Option<Duration>;
+
+    /// The iteration the animation is at `elapsed` after it started.
+    fn get_iteration(&self, _elapsed: Duration) -> u32 {
+        0
+    }
+
+    /// How far the animation is `elapsed` after it started, from `0.0` to
+    /// `1.0`. For an animation repeating forever, it's how far the current
+    /// iteration is.
+    fn get_progress(&self, elapsed: Duration) -> f32 {
+        match self.get_duration() {
+            Some(duration) if duration.is_zero() => 1.0,
+            Some(duration) => elapsed.div_duration_f32(duration).min(1.0),
+            None => 0.0,
+        }
+    }
 }
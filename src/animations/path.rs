@@ -1,19 +1,25 @@
 use super::Animation;
-use crate::{Curve, Tween, Value};
+use crate::{
+    AnimationDirection, Curve, IterationCount, MotionCurve, MotionDuration, MotionTheme, Repeat,
+    Tween, Value,
+};
 use indexmap::IndexMap;
-use std::ops::Range;
+use std::{ops::Range, time::Duration};
 
 struct Segment {
     value: Value,
     curve: Curve,
-    duration: u64,
+    duration: Duration,
 }
 
 #[derive(Default)]
 pub struct PathAnimation {
     initial_value: Option<Value>,
-    segments: IndexMap<Range<u64>, Segment>,
-    duration: u64,
+    segments: IndexMap<Range<Duration>, Segment>,
+    duration: Duration,
+    repeat: Repeat,
+    /// The value of the tween when the animation started.
+    origin: Option<Value>,
 }
 
 impl PathAnimation {
@@ -27,33 +33,31 @@ impl PathAnimation {
         self
     }
 
-    pub fn insert<V: Into<Value>>(mut self, value: V, curve: Curve, duration: u64) -> Self {
-        let last = self
-            .segments
-            .last()
-            .map(|(key, _)| key.end)
-            .unwrap_or_default();
-
-        self.duration += duration;
-        self.segments.insert(
-            last..(last + duration),
-            Segment {
-                value: value.into(),
-                curve,
-                duration,
-            },
-        );
-
-        self
+    /// Appends a segment, resolving tokens with [`MotionTheme::with_current`].
+    pub fn insert<V: Into<Value>>(
+        self,
+        value: V,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
+    ) -> Self {
+        self.insert_delayed(value, curve, duration, Duration::ZERO)
     }
 
+    /// Appends a segment starting `delay` after the end of the previous one,
+    /// resolving tokens with [`MotionTheme::with_current`].
     pub fn insert_delayed<V: Into<Value>>(
         mut self,
         value: V,
-        curve: Curve,
-        duration: u64,
-        delay: u64,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
+        delay: Duration,
     ) -> Self {
+        let (curve, duration) = MotionTheme::with_current(|theme| {
+            (
+                theme.resolve_curve(&curve.into()),
+                theme.resolve_duration(&duration.into()),
+            )
+        });
         let last = self
             .segments
             .last()
@@ -72,6 +76,146 @@ impl PathAnimation {
 
         self
     }
+
+    /// Plays the path `count` times, like the CSS `animation-iteration-count`.
+    pub fn repeat(mut self, count: impl Into<IterationCount>) -> Self {
+        self.repeat.iterations = count.into();
+
+        self
+    }
+
+    /// Plays every other iteration backwards, going back and forth.
+    pub const fn alternate(mut self) -> Self {
+        self.repeat.direction = AnimationDirection::Alternate;
+
+        self
+    }
+
+    pub const fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.repeat.direction = direction;
+
+        self
+    }
+
+    /// Waits `delay` between two iterations.
+    pub const fn repeat_delay(mut self, delay: Duration) -> Self {
+        self.repeat.delay = delay;
+
+        self
+    }
+
+    /// Starts at `iteration`, `0.5` starting halfway through the first one.
+    pub const fn iteration_start(mut self, iteration: f32) -> Self {
+        self.repeat.iteration_start = iteration;
+
+        self
+    }
+
+    /// The value of the path `time` into an iteration.
+    fn value_at(&self, origin: &Value, time: Duration) -> Value {
+        let mut value = origin;
+
+        for (key, segment) in &self.segments {
+            // Waiting for the segment to start
+            if time < key.start {
+                break;
+            }
+
+            if time < key.end {
+                let mut tween = Tween::new(value.clone(), segment.value.clone())
+                    .curve(segment.curve.clone())
+                    .duration(segment.duration);
+
+                tween.advance(time - key.start);
+
+                return tween.value;
+            }
+
+            value = &segment.value;
+        }
+
+        value.clone()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SegmentDefinition {
+    value: Value,
+    #[serde(deserialize_with = "crate::serialization::known_curve")]
+    curve: MotionCurve,
+    #[serde(deserialize_with = "crate::serialization::known_duration")]
+    duration: MotionDuration,
+    #[serde(
+        default,
+        with = "crate::serialization::millis",
+        skip_serializing_if = "Duration::is_zero"
+    )]
+    delay: Duration,
+}
+
+/// Stored as its initial value and its list of segments, each with the delay
+/// separating it from the previous one.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PathAnimationDefinition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    initial: Option<Value>,
+    segments: Vec<SegmentDefinition>,
+    #[serde(default, skip_serializing_if = "Repeat::is_once")]
+    repeat: Repeat,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PathAnimation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut last = Duration::ZERO;
+
+        PathAnimationDefinition {
+            initial: self.initial_value.clone(),
+            segments: self
+                .segments
+                .iter()
+                .map(|(key, segment)| {
+                    let delay = key.start - last;
+
+                    last = key.end;
+
+                    SegmentDefinition {
+                        value: segment.value.clone(),
+                        curve: segment.curve.clone().into(),
+                        duration: segment.duration.into(),
+                        delay,
+                    }
+                })
+                .collect(),
+            repeat: self.repeat,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PathAnimation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let definition = PathAnimationDefinition::deserialize(deserializer)?;
+        let mut animation = Self {
+            initial_value: definition.initial,
+            repeat: definition.repeat,
+            ..Self::default()
+        };
+
+        for segment in definition.segments {
+            animation = animation.insert_delayed(
+                segment.value,
+                segment.curve,
+                segment.duration,
+                segment.delay,
+            );
+        }
+
+        Ok(animation)
+    }
 }
 
 impl Animation for PathAnimation {
@@ -81,29 +225,28 @@ impl Animation for PathAnimation {
         }
     }
 
-    fn advance(&mut self, tween: &mut Tween, index: u128) {
-        if let Some((key, current_segment)) = self
-            .segments
-            .iter()
-            .find(|(key, _)| key.contains(&(index as u64)))
-        {
-            if tween.curve != current_segment.curve {
-                tween.curve = current_segment.curve.clone();
-            }
+    fn advance(&mut self, tween: &mut Tween, elapsed: Duration) {
+        let origin = self
+            .origin
+            .get_or_insert_with(|| tween.value.clone())
+            .clone();
+        let progress = self.repeat.position(elapsed, self.duration).progress;
+        let value = self.value_at(&origin, self.duration.mul_f64(progress));
 
-            if tween.destination != current_segment.value {
-                tween.to(current_segment.value.clone());
-            }
+        if tween.value != value {
+            tween.value = value;
+        }
+    }
 
-            if tween.duration != current_segment.duration as f32 {
-                tween.set_duration(current_segment.duration);
-            }
+    fn get_duration(&self) -> Option<Duration> {
+        self.repeat.total_duration(self.duration)
+    }
 
-            tween.advance((index as u64 - key.start) as f32);
-        }
+    fn get_iteration(&self, elapsed: Duration) -> u32 {
+        self.repeat.position(elapsed, self.duration).iteration
     }
 
-    fn get_duration(&self) -> u64 {
-        self.duration
+    fn get_progress(&self, elapsed: Duration) -> f32 {
+        self.repeat.progress(elapsed, self.duration)
     }
 }
@@ -0,0 +1,158 @@
+use freya::prelude::{use_hook, CopyValue, Readable, Writable};
+use indexmap::IndexMap;
+use std::{cell::RefCell, panic::Location, rc::Rc};
+
+/// What happened to an animation, passed to the callbacks registered with
+/// the `on_event` and `on_key_event` methods of the animation handles.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AnimationEvent {
+    Start,
+    /// Sent on every frame with the progress of the animation, from `0.0` to
+    /// `1.0`. For animations repeating forever, it's the progress of the
+    /// current iteration.
+    Update(f32),
+    /// Sent when a repeating animation starts another iteration, with its
+    /// index.
+    Iteration(u32),
+    Complete,
+    /// Sent when the animation is cancelled, replaced by another one or its
+    /// component is unmounted.
+    Cancel,
+}
+
+type Callback = Rc<RefCell<dyn FnMut(AnimationEvent)>>;
+
+/// The callbacks registered with an animation handle, by the place they were
+/// registered from and the key they listen to.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Callbacks {
+    callbacks: CopyValue<IndexMap<(&'static Location<'static>, Option<String>), Callback>>,
+}
+
+impl Callbacks {
+    pub(crate) fn register(
+        &self,
+        location: &'static Location<'static>,
+        key: Option<String>,
+        callback: impl FnMut(AnimationEvent) + 'static,
+    ) {
+        let mut callbacks = self.callbacks;
+
+        callbacks
+            .write()
+            .insert((location, key), Rc::new(RefCell::new(callback)));
+    }
+
+    /// Calls the callbacks of the handle, or of `key`.
+    pub(crate) fn emit(&self, key: Option<&str>, event: AnimationEvent) {
+        // Callbacks can register callbacks, so they run outside of the map
+        let callbacks = match self.callbacks.try_peek() {
+            Ok(callbacks) => callbacks
+                .iter()
+                .filter(|((_, callback_key), _)| callback_key.as_deref() == key)
+                .map(|(_, callback)| callback.clone())
+                .collect::<Vec<_>>(),
+            // The component is gone already
+            Err(_) => return,
+        };
+
+        for callback in callbacks {
+            // A callback causing its own event isn't called again
+            if let Ok(mut callback) = callback.try_borrow_mut() {
+                callback(event);
+            }
+        }
+    }
+}
+
+pub(crate) fn use_callbacks() -> Callbacks {
+    use_hook(|| Callbacks {
+        callbacks: CopyValue::new(IndexMap::new()),
+    })
+}
+
+/// The lifecycle of a played animation and its keys.
+///
+/// It sends [`AnimationEvent::Cancel`] to the keys which didn't complete and
+/// to the handle when dropped before completing, which happens when its job is
+/// cancelled.
+pub(crate) struct Lifecycle {
+    callbacks: Callbacks,
+    pending: Vec<String>,
+    is_complete: bool,
+}
+
+impl Lifecycle {
+    /// Sends [`AnimationEvent::Start`] to the handle and `keys`.
+    pub(crate) fn start(callbacks: Callbacks, keys: impl IntoIterator<Item = String>) -> Self {
+        let pending = keys.into_iter().collect::<Vec<_>>();
+
+        callbacks.emit(None, AnimationEvent::Start);
+
+        for key in &pending {
+            callbacks.emit(Some(key), AnimationEvent::Start);
+        }
+
+        Self {
+            callbacks,
+            pending,
+            is_complete: false,
+        }
+    }
+
+    pub(crate) fn update(&self, progress: f32) {
+        self.callbacks.emit(None, AnimationEvent::Update(progress));
+    }
+
+    pub(crate) fn update_key(&self, key: &str, progress: f32) {
+        if self.pending.iter().any(|pending| pending == key) {
+            self.callbacks
+                .emit(Some(key), AnimationEvent::Update(progress));
+        }
+    }
+
+    pub(crate) fn iteration(&self, iteration: u32) {
+        self.callbacks
+            .emit(None, AnimationEvent::Iteration(iteration));
+    }
+
+    pub(crate) fn iteration_key(&self, key: &str, iteration: u32) {
+        if self.pending.iter().any(|pending| pending == key) {
+            self.callbacks
+                .emit(Some(key), AnimationEvent::Iteration(iteration));
+        }
+    }
+
+    pub(crate) fn complete_key(&mut self, key: &str) {
+        if let Some(index) = self.pending.iter().position(|pending| pending == key) {
+            self.pending.remove(index);
+
+            self.callbacks.emit(Some(key), AnimationEvent::Complete);
+        }
+    }
+
+    /// Completes the remaining keys, then the animation.
+    pub(crate) fn complete(&mut self) {
+        for key in std::mem::take(&mut self.pending) {
+            self.callbacks.emit(Some(&key), AnimationEvent::Complete);
+        }
+
+        self.is_complete = true;
+
+        self.callbacks.emit(None, AnimationEvent::Complete);
+    }
+}
+
+impl Drop for Lifecycle {
+    fn drop(&mut self) {
+        if self.is_complete {
+            return;
+        }
+
+        for key in &self.pending {
+            self.callbacks.emit(Some(key), AnimationEvent::Cancel);
+        }
+
+        self.callbacks.emit(None, AnimationEvent::Cancel);
+    }
+}
@@ -0,0 +1,242 @@
+use freya::prelude::{try_consume_context, use_context_provider, Readable, Signal, Writable};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// The time animations are played against, provided to the app with
+/// [`use_animation_clock`].
+///
+/// It follows the wall clock by default, or a [`ManualClock`] in tests, and
+/// can be slowed down, sped up, paused and stepped frame by frame to inspect
+/// animations.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AnimationClock {
+    state: Option<Signal<ClockState>>,
+}
+
+struct ClockState {
+    source: Option<ManualClock>,
+    /// Time of the clock when the source was at `source_base`.
+    base: Duration,
+    source_base: Duration,
+    time_scale: f32,
+    is_paused: bool,
+}
+
+impl ClockState {
+    fn new(source: Option<ManualClock>) -> Self {
+        let source_base = source.as_ref().map_or_else(wall_clock, ManualClock::now);
+
+        Self {
+            source,
+            base: Duration::ZERO,
+            source_base,
+            time_scale: 1.0,
+            is_paused: false,
+        }
+    }
+
+    fn source_now(&self) -> Duration {
+        self.source
+            .as_ref()
+            .map_or_else(wall_clock, ManualClock::now)
+    }
+
+    fn now(&self) -> Duration {
+        if self.is_paused {
+            self.base
+        } else {
+            self.base
+                + self
+                    .source_now()
+                    .saturating_sub(self.source_base)
+                    .mul_f32(self.time_scale)
+        }
+    }
+
+    /// Moves the base to the current time, so that changes to the pace of the
+    /// clock only apply from now on.
+    fn rebase(&mut self) {
+        self.base = self.now();
+        self.source_base = self.source_now();
+    }
+}
+
+/// Time elapsed since the first time an animation asked for it.
+fn wall_clock() -> Duration {
+    static START: OnceLock<Instant> = OnceLock::new();
+
+    START.get_or_init(Instant::now).elapsed()
+}
+
+/// A clock which only moves when told to, to test animations
+/// deterministically.
+///
+/// It's a cheap handle which can be created by a test, provided to the app
+/// under test with [`use_manual_animation_clock`] and advanced from the test,
+/// for example with `freya-testing`:
+///
+/// ```ignore
+/// static CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+///
+/// fn app() -> Element {
+///     use_manual_animation_clock(|| CLOCK.clone());
+///
+///     // Fades `opacity` from 0 to 1 in 500ms, shown by a label
+///     rsx! { FadingLabel {} }
+/// }
+///
+/// let mut utils = launch_test(app);
+///
+/// utils.wait_for_update().await;
+/// CLOCK.advance(Duration::from_millis(250));
+/// utils.wait_for_update().await;
+/// utils.wait_for_update().await;
+///
+/// assert_eq!(utils.root().get(0).get(0).text(), Some("0.5"));
+/// ```
+///
+/// The running animations still wait for the next frame before reading the
+/// time, which `wait_for_update` triggers in headless tests, and the app
+/// renders the new values on the update after it.
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    nanos: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn now(&self) -> Duration {
+        Duration::from_nanos(self.nanos.load(Ordering::Acquire))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn advance(&self, duration: Duration) {
+        self.nanos
+            .fetch_add(duration.as_nanos() as u64, Ordering::AcqRel);
+    }
+}
+
+impl AnimationClock {
+    /// How far [`AnimationClock::step`] moves the clock.
+    pub const FRAME: Duration = Duration::from_micros(16_667);
+
+    /// The clock provided by [`use_animation_clock`] or
+    /// [`use_manual_animation_clock`] in an ancestor component, or one
+    /// following the wall clock if there's none.
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            state: try_consume_context::<Signal<ClockState>>(),
+        }
+    }
+
+    /// The current time of the clock.
+    #[must_use]
+    pub fn now(&self) -> Duration {
+        self.state
+            .map_or_else(wall_clock, |state| state.peek().now())
+    }
+
+    #[must_use]
+    pub fn time_scale(&self) -> f32 {
+        self.state.map_or(1.0, |state| state.read().time_scale)
+    }
+
+    /// Plays every animation `time_scale` times faster, `0.1` making them ten
+    /// times slower.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time_scale` is negative or not finite, or if the clock
+    /// wasn't provided with [`use_animation_clock`] or
+    /// [`use_manual_animation_clock`].
+    pub fn set_time_scale(&self, time_scale: f32) {
+        assert!(
+            time_scale.is_finite() && time_scale >= 0.0,
+            "time scale must be finite and non-negative"
+        );
+
+        let mut state = self.state();
+        let mut state = state.write();
+
+        state.rebase();
+        state.time_scale = time_scale;
+    }
+
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.state.is_some_and(|state| state.read().is_paused)
+    }
+
+    /// Freezes every animation where it is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock wasn't provided with [`use_animation_clock`] or
+    /// [`use_manual_animation_clock`].
+    pub fn pause_all(&self) {
+        let mut state = self.state();
+        let mut state = state.write();
+
+        state.rebase();
+        state.is_paused = true;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the clock wasn't provided with [`use_animation_clock`] or
+    /// [`use_manual_animation_clock`].
+    pub fn resume_all(&self) {
+        let mut state = self.state();
+        let mut state = state.write();
+
+        state.rebase();
+        state.is_paused = false;
+    }
+
+    /// Moves a paused clock forward by one [`AnimationClock::FRAME`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock wasn't provided with [`use_animation_clock`] or
+    /// [`use_manual_animation_clock`].
+    pub fn step(&self) {
+        let mut state = self.state();
+        let mut state = state.write();
+
+        if state.is_paused {
+            state.base += Self::FRAME;
+        }
+    }
+
+    fn state(&self) -> Signal<ClockState> {
+        self.state
+            .expect("the animation clock must be provided with use_animation_clock")
+    }
+}
+
+/// Provides an [`AnimationClock`] to the descendants of the current
+/// component, usually at the root of the app.
+pub fn use_animation_clock() -> AnimationClock {
+    let state = use_context_provider(|| Signal::new(ClockState::new(None)));
+
+    AnimationClock { state: Some(state) }
+}
+
+/// Provides an [`AnimationClock`] following a [`ManualClock`] to the
+/// descendants of the current component, see [`ManualClock`].
+pub fn use_manual_animation_clock(clock: impl FnOnce() -> ManualClock) -> AnimationClock {
+    let state = use_context_provider(|| Signal::new(ClockState::new(Some(clock()))));
+
+    AnimationClock { state: Some(state) }
+}
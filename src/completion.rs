@@ -0,0 +1,106 @@
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+/// How a played animation ended.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnimationOutcome {
+    /// It played until the end.
+    Completed,
+    /// It was cancelled, or its component was unmounted.
+    Cancelled,
+    /// It was replaced by another animation of the same values.
+    Interrupted,
+}
+
+#[derive(Default)]
+struct State {
+    outcome: Option<AnimationOutcome>,
+    waker: Option<Waker>,
+}
+
+/// Resolves with the [`AnimationOutcome`] of a played animation.
+///
+/// ```ignore
+/// spawn(async move {
+///     if fade.play_all().await == AnimationOutcome::Completed {
+///         router.push(Route::Home);
+///     }
+/// });
+/// ```
+///
+/// Dropping it doesn't stop the animation.
+pub struct Completion {
+    state: Rc<RefCell<State>>,
+}
+
+impl Completion {
+    /// The outcome of the animation, if it ended.
+    #[must_use]
+    pub fn outcome(&self) -> Option<AnimationOutcome> {
+        self.state.borrow().outcome
+    }
+}
+
+impl Future for Completion {
+    type Output = AnimationOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(outcome) = state.outcome {
+            Poll::Ready(outcome)
+        } else {
+            state.waker = Some(cx.waker().clone());
+
+            Poll::Pending
+        }
+    }
+}
+
+/// The end of a [`Completion`] kept by the animation. It resolves it as
+/// [`AnimationOutcome::Cancelled`] when dropped.
+pub(crate) struct Completer {
+    state: Rc<RefCell<State>>,
+}
+
+impl Completer {
+    pub(crate) fn new() -> (Self, Completion) {
+        let state = Rc::new(RefCell::new(State::default()));
+
+        (
+            Self {
+                state: state.clone(),
+            },
+            Completion { state },
+        )
+    }
+
+    /// Resolves the completion, unless it already was.
+    pub(crate) fn complete(&self, outcome: AnimationOutcome) {
+        let waker = {
+            let mut state = self.state.borrow_mut();
+
+            if state.outcome.is_some() {
+                return;
+            }
+
+            state.outcome = Some(outcome);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Completer {
+    fn drop(&mut self) {
+        self.complete(AnimationOutcome::Cancelled);
+    }
+}
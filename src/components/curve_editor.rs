@@ -0,0 +1,236 @@
+use crate::{preview::CurvePreview, use_transition, Curve};
+use freya::prelude::*;
+use std::{fmt::Write, time::Duration};
+
+/// Values always visible on the y axis of the editor, leaving room for
+/// handles that overshoot.
+const RANGE: (f32, f32) = (-0.5, 1.5);
+const HANDLE_SIZE: f32 = 14.0;
+const DOT_SIZE: f32 = 16.0;
+const PREVIEW_DURATION: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Handle {
+    First,
+    Second,
+}
+
+/// Maps between curve coordinates and pixels inside of the editor's plot,
+/// using the same y range as the preview drawn behind it.
+#[derive(Clone, Copy)]
+struct Plot {
+    size: f32,
+    range: (f32, f32),
+}
+
+impl Plot {
+    fn scale(self) -> f32 {
+        self.size - crate::preview::PADDING * 2.0
+    }
+
+    fn to_pixels(self, (t, y): (f32, f32)) -> (f32, f32) {
+        (
+            t.mul_add(self.scale(), crate::preview::PADDING),
+            ((self.range.1 - y) / (self.range.1 - self.range.0))
+                .mul_add(self.scale(), crate::preview::PADDING),
+        )
+    }
+
+    fn from_pixels(self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            ((x - crate::preview::PADDING) / self.scale()).clamp(0.0, 1.0),
+            ((y - crate::preview::PADDING) / self.scale())
+                .mul_add(self.range.0 - self.range.1, self.range.1)
+                .clamp(self.range.0, self.range.1),
+        )
+    }
+}
+
+/// An interactive editor for cubic Bézier curves.
+///
+/// The control points can be dragged around or picked from the cubic
+/// [`Curve::PRESETS`], and every change is previewed with a dot animated with
+/// the resulting curve, once the handle is released when dragging. Curves that aren't cubic Béziers start from a linear
+/// curve.
+#[allow(
+    non_snake_case,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+#[component]
+pub fn CurveEditor(
+    /// The edited curve.
+    value: Curve,
+    /// Called with the new curve every time it's changed.
+    onchange: EventHandler<Curve>,
+    /// Width and height of the plot.
+    #[props(default = 320.0)]
+    size: f32,
+) -> Element {
+    let (node_reference, layout) = use_node();
+    let mut dragging = use_signal(|| None::<Handle>);
+
+    let animation = use_transition(|context| {
+        context.add_tween("progress", 0.0, Curve::LINEAR, PREVIEW_DURATION);
+    });
+
+    let replay = move |curve: Curve| {
+        animation.set_curve("progress", curve);
+        animation.forced_set("progress", 0.0);
+        animation.play([("progress", 1.0)]);
+    };
+
+    use_effect(use_reactive((&value,), move |(value,)| {
+        if dragging.peek().is_none() {
+            replay(value);
+        }
+    }));
+
+    let (first, second) = match &value {
+        Curve::Cubic(cubic) => cubic.control_points(),
+        _ => ((0.0, 0.0), (1.0, 1.0)),
+    };
+
+    let preview = CurvePreview::new(&value)
+        .size(size as u32, size as u32)
+        .range(
+            RANGE.0.min(first.1).min(second.1),
+            RANGE.1.max(first.1).max(second.1),
+        );
+    let plot = Plot {
+        size,
+        range: preview.visible_range(),
+    };
+
+    let onglobalmousemove = move |event: MouseEvent| {
+        let Some(handle) = *dragging.read() else {
+            return;
+        };
+
+        let cursor = event.get_screen_coordinates();
+
+        let point = plot.from_pixels((
+            cursor.x as f32 - layout.area.min_x(),
+            cursor.y as f32 - layout.area.min_y(),
+        ));
+
+        let ((a, b), (c, d)) = match handle {
+            Handle::First => (point, second),
+            Handle::Second => (first, point),
+        };
+
+        onchange.call(Curve::cubic(a, b, c, d));
+    };
+
+    let mut svg = preview.to_svg();
+
+    svg.truncate(svg.len() - "</svg>".len());
+
+    for (from, to) in [((0.0, 0.0), first), ((1.0, 1.0), second)] {
+        let (from, to) = (plot.to_pixels(from), plot.to_pixels(to));
+
+        let _ = write!(
+            svg,
+            r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#757575" stroke-width="1.5"/>"##,
+            from.0, from.1, to.0, to.1
+        );
+    }
+
+    svg.push_str("</svg>");
+
+    let css = value
+        .to_css()
+        .unwrap_or_else(|| String::from("no CSS equivalent"));
+    let progress = animation.get::<f32>("progress");
+    let dot_left = progress * (size - DOT_SIZE);
+
+    let handles = [(Handle::First, first), (Handle::Second, second)].map(|(handle, point)| {
+        let (x, y) = plot.to_pixels(point);
+
+        (handle, x - HANDLE_SIZE / 2.0, y - HANDLE_SIZE / 2.0)
+    });
+
+    rsx! {
+        rect {
+            direction: "horizontal",
+            spacing: "16",
+            rect {
+                spacing: "12",
+                rect {
+                    reference: node_reference,
+                    width: "{size}",
+                    height: "{size}",
+                    corner_radius: "8",
+                    overflow: "clip",
+                    onglobalmousemove,
+                    onglobalclick: {
+                        let value = value.clone();
+
+                        move |_| {
+                            if dragging.take().is_some() {
+                                replay(value.clone());
+                            }
+                        }
+                    },
+                    svg {
+                        width: "{size}",
+                        height: "{size}",
+                        svg_data: dynamic_bytes(svg.into_bytes()),
+                    }
+                    for (handle, left, top) in handles {
+                        rect {
+                            position: "absolute",
+                            position_left: "{left}",
+                            position_top: "{top}",
+                            width: "{HANDLE_SIZE}",
+                            height: "{HANDLE_SIZE}",
+                            corner_radius: "{HANDLE_SIZE / 2.0}",
+                            background: "rgb(30, 136, 229)",
+                            border: "2 inner white",
+                            onmousedown: move |_| dragging.set(Some(handle)),
+                        }
+                    }
+                }
+                rect {
+                    width: "{size}",
+                    height: "{DOT_SIZE}",
+                    corner_radius: "{DOT_SIZE / 2.0}",
+                    background: "rgb(238, 238, 238)",
+                    onclick: {
+                        let value = value.clone();
+
+                        move |_| replay(value.clone())
+                    },
+                    rect {
+                        width: "{DOT_SIZE}",
+                        height: "{DOT_SIZE}",
+                        corner_radius: "{DOT_SIZE / 2.0}",
+                        background: "rgb(251, 140, 0)",
+                        margin: "0 0 0 {dot_left}",
+                    }
+                }
+                label {
+                    font_family: "monospace",
+                    "{css}"
+                }
+            }
+            ScrollView {
+                width: "200",
+                height: "{size}",
+                for (name, preset) in Curve::PRESETS.iter().filter(|(_, curve)| matches!(curve, Curve::Cubic(_))) {
+                    rect {
+                        key: "{name}",
+                        width: "fill",
+                        padding: "6 10",
+                        corner_radius: "6",
+                        background: if *preset == value { "rgb(227, 242, 253)" } else { "transparent" },
+                        onclick: move |_| onchange.call(preset.clone()),
+                        label {
+                            "{name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
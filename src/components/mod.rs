@@ -0,0 +1,3 @@
+pub use self::curve_editor::CurveEditor;
+
+mod curve_editor;
@@ -0,0 +1,127 @@
+use super::ParametricCurve;
+
+#[derive(Clone, PartialEq)]
+pub struct BackInCurve {
+    overshoot: f32,
+}
+
+impl BackInCurve {
+    #[must_use]
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(1.70158)
+    }
+
+    #[must_use]
+    pub const fn overshoot(&self) -> f32 {
+        self.overshoot
+    }
+}
+
+impl ParametricCurve<f32> for BackInCurve {
+    fn transform_internal(&self, t: f32) -> f32 {
+        t.powi(2) * (self.overshoot + 1.0).mul_add(t, -self.overshoot)
+    }
+
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        Some(t * (3.0 * (self.overshoot + 1.0)).mul_add(t, -2.0 * self.overshoot))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct BackOutCurve {
+    overshoot: f32,
+}
+
+impl BackOutCurve {
+    #[must_use]
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(1.70158)
+    }
+
+    #[must_use]
+    pub const fn overshoot(&self) -> f32 {
+        self.overshoot
+    }
+}
+
+impl ParametricCurve<f32> for BackOutCurve {
+    fn transform_internal(&self, mut t: f32) -> f32 {
+        t -= 1.0;
+
+        t.powi(2)
+            .mul_add((self.overshoot + 1.0).mul_add(t, self.overshoot), 1.0)
+    }
+
+    fn derivative_internal(&self, mut t: f32) -> Option<f32> {
+        t -= 1.0;
+
+        Some(t * (3.0 * (self.overshoot + 1.0)).mul_add(t, 2.0 * self.overshoot))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct BackInOutCurve {
+    overshoot: f32,
+}
+
+impl BackInOutCurve {
+    #[must_use]
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(1.70158)
+    }
+
+    #[must_use]
+    pub const fn overshoot(&self) -> f32 {
+        self.overshoot
+    }
+}
+
+impl ParametricCurve<f32> for BackInOutCurve {
+    fn transform_internal(&self, mut t: f32) -> f32 {
+        // Each half only covers half of the distance, so the overshoot is scaled
+        // up to keep it visually similar to the in and out variants.
+        let overshoot = self.overshoot * 1.525;
+
+        t *= 2.0;
+
+        if t < 1.0 {
+            0.5 * t.powi(2) * (overshoot + 1.0).mul_add(t, -overshoot)
+        } else {
+            t -= 2.0;
+
+            0.5 * t
+                .powi(2)
+                .mul_add((overshoot + 1.0).mul_add(t, overshoot), 2.0)
+        }
+    }
+
+    fn derivative_internal(&self, mut t: f32) -> Option<f32> {
+        let overshoot = self.overshoot * 1.525;
+
+        t *= 2.0;
+
+        // Each half is scaled by 0.5 on the y axis and by 2.0 on the x axis.
+        if t < 1.0 {
+            Some(t * (3.0 * (overshoot + 1.0)).mul_add(t, -2.0 * overshoot))
+        } else {
+            t -= 2.0;
+
+            Some(t * (3.0 * (overshoot + 1.0)).mul_add(t, 2.0 * overshoot))
+        }
+    }
+}
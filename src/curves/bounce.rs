@@ -1,50 +1,181 @@
 use super::ParametricCurve;
 
-fn _bounce(mut t: f32) -> f32 {
-    if t < 1.0 / 2.75 {
-        7.5625 * t.powi(2)
-    } else if t < 2.0 / 2.75 {
-        t -= 1.5 / 2.75;
+/// A ball falling from `0.0` onto `1.0` and bouncing `bounces` times, each
+/// impact keeping `restitution` of its velocity.
+///
+/// With 3 bounces and a restitution of `0.5` this is the classic Penner bounce.
+fn _bounce(t: f32, bounces: usize, restitution: f32) -> f32 {
+    // Every bounce is `restitution` times shorter than the previous one, and the
+    // fall takes half of a bounce with a restitution of 1.
+    let mut total = 1.0;
+    let mut scale = 1.0;
 
-        7.5625f32.mul_add(t.powi(2), 0.75)
-    } else if t < 2.5 / 2.75 {
-        t -= 2.25 / 2.75;
+    for _ in 0..bounces {
+        scale *= restitution;
+        total += 2.0 * scale;
+    }
+
+    let fall = 1.0 / total;
+
+    if t < fall {
+        return (t / fall).powi(2);
+    }
+
+    let mut start = fall;
+    let mut scale = 1.0;
+
+    for _ in 0..bounces {
+        scale *= restitution;
 
-        7.5625f32.mul_add(t.powi(2), 0.9375)
-    } else {
-        t -= 2.625 / 2.75;
+        let half_width = fall * scale;
 
-        7.5625f32.mul_add(t.powi(2), 0.984375)
+        if t < 2.0f32.mul_add(half_width, start) {
+            let x = (t - start - half_width) / half_width;
+
+            return scale.powi(2).mul_add(x.mul_add(x, -1.0), 1.0);
+        }
+
+        start = 2.0f32.mul_add(half_width, start);
     }
+
+    1.0
 }
 
 #[derive(Clone, PartialEq)]
-pub struct BounceInCurve;
+pub struct BounceInCurve {
+    bounces: usize,
+    restitution: f32,
+}
+
+impl BounceInCurve {
+    /// # Panics
+    ///
+    /// Panics if `restitution` is outside of `0.0..=1.0`.
+    #[must_use]
+    pub const fn new(bounces: usize, restitution: f32) -> Self {
+        assert!(
+            restitution >= 0.0 && restitution <= 1.0,
+            "expected restitution in 0..=1"
+        );
+
+        Self {
+            bounces,
+            restitution,
+        }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(3, 0.5)
+    }
+
+    #[must_use]
+    pub const fn bounces(&self) -> usize {
+        self.bounces
+    }
+
+    #[must_use]
+    pub const fn restitution(&self) -> f32 {
+        self.restitution
+    }
+}
 
 impl ParametricCurve<f32> for BounceInCurve {
     fn transform_internal(&self, t: f32) -> f32 {
-        1.0 - _bounce(1.0 - t)
+        1.0 - _bounce(1.0 - t, self.bounces, self.restitution)
     }
 }
 
 #[derive(Clone, PartialEq)]
-pub struct BounceOutCurve;
+pub struct BounceOutCurve {
+    bounces: usize,
+    restitution: f32,
+}
+
+impl BounceOutCurve {
+    /// # Panics
+    ///
+    /// Panics if `restitution` is outside of `0.0..=1.0`.
+    #[must_use]
+    pub const fn new(bounces: usize, restitution: f32) -> Self {
+        assert!(
+            restitution >= 0.0 && restitution <= 1.0,
+            "expected restitution in 0..=1"
+        );
+
+        Self {
+            bounces,
+            restitution,
+        }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(3, 0.5)
+    }
+
+    #[must_use]
+    pub const fn bounces(&self) -> usize {
+        self.bounces
+    }
+
+    #[must_use]
+    pub const fn restitution(&self) -> f32 {
+        self.restitution
+    }
+}
 
 impl ParametricCurve<f32> for BounceOutCurve {
     fn transform_internal(&self, t: f32) -> f32 {
-        _bounce(t)
+        _bounce(t, self.bounces, self.restitution)
     }
 }
 
 #[derive(Clone, PartialEq)]
-pub struct BounceInOutCurve;
+pub struct BounceInOutCurve {
+    bounces: usize,
+    restitution: f32,
+}
+
+impl BounceInOutCurve {
+    /// # Panics
+    ///
+    /// Panics if `restitution` is outside of `0.0..=1.0`.
+    #[must_use]
+    pub const fn new(bounces: usize, restitution: f32) -> Self {
+        assert!(
+            restitution >= 0.0 && restitution <= 1.0,
+            "expected restitution in 0..=1"
+        );
+
+        Self {
+            bounces,
+            restitution,
+        }
+    }
+
+    #[must_use]
+    pub const fn default() -> Self {
+        Self::new(3, 0.5)
+    }
+
+    #[must_use]
+    pub const fn bounces(&self) -> usize {
+        self.bounces
+    }
+
+    #[must_use]
+    pub const fn restitution(&self) -> f32 {
+        self.restitution
+    }
+}
 
 impl ParametricCurve<f32> for BounceInOutCurve {
     fn transform_internal(&self, t: f32) -> f32 {
         if t < 0.5 {
-            (1.0 - _bounce(t.mul_add(-2.0, 1.0))) * 0.5
+            (1.0 - _bounce(t.mul_add(-2.0, 1.0), self.bounces, self.restitution)) * 0.5
         } else {
-            _bounce(t.mul_add(2.0, -1.0)).mul_add(0.5, 0.5)
+            _bounce(t.mul_add(2.0, -1.0), self.bounces, self.restitution).mul_add(0.5, 0.5)
         }
     }
 }
@@ -0,0 +1,150 @@
+use super::{sampled::hermite, ParametricCurve};
+
+/// A Catmull-Rom spline going from `(0.0, 0.0)` to `(1.0, 1.0)` through the
+/// given control points.
+///
+/// `tension` ranges from `0.0` (a regular Catmull-Rom spline) to `1.0`, at
+/// which the spline degenerates into straight lines between the points.
+#[derive(Clone, PartialEq)]
+pub struct CatmullRom {
+    points: Vec<(f32, f32)>,
+    tension: f32,
+}
+
+impl CatmullRom {
+    const VALIDATION_SAMPLES: usize = 32;
+    const SOLVE_ITERATIONS: usize = 32;
+    const SOLVE_ERROR_BOUND: f32 = 1e-6;
+
+    /// # Panics
+    ///
+    /// Panics if the spline is invalid, see [`CatmullRom::try_new`].
+    #[must_use]
+    pub fn new<P: IntoIterator<Item = (f32, f32)>>(control_points: P, tension: f32) -> Self {
+        Self::try_new(control_points, tension).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Builds the spline, or explains why it can't be built: `tension` has to
+    /// be in `0.0..=1.0`, the control points have to lie between `0.0` and
+    /// `1.0` on the x axis, and the resulting spline has to be a function of
+    /// `t` (it can't have several values for the same `t`).
+    pub fn try_new<P: IntoIterator<Item = (f32, f32)>>(
+        control_points: P,
+        tension: f32,
+    ) -> Result<Self, &'static str> {
+        if !(0.0..=1.0).contains(&tension) {
+            return Err("expected tension in 0..=1");
+        }
+
+        let mut points = vec![(0.0, 0.0)];
+
+        for (t, y) in control_points {
+            if !(t > 0.0 && t < 1.0 && y.is_finite()) {
+                return Err("expected control points to lie between 0.0 and 1.0");
+            }
+
+            points.push((t, y));
+        }
+
+        points.push((1.0, 1.0));
+
+        let curve = Self { points, tension };
+
+        if !curve.is_single_valued() {
+            return Err("control points produce a curve that isn't single-valued in t");
+        }
+
+        Ok(curve)
+    }
+
+    #[must_use]
+    pub fn control_points(&self) -> &[(f32, f32)] {
+        &self.points[1..self.points.len() - 1]
+    }
+
+    #[must_use]
+    pub const fn tension(&self) -> f32 {
+        self.tension
+    }
+
+    /// Returns the points and tangents of the `index`-th segment.
+    fn segment(&self, index: usize) -> [(f32, f32); 4] {
+        let last = self.points.len() - 1;
+        let (p1, p2) = (self.points[index], self.points[index + 1]);
+
+        // The spline is extended past its ends by reflecting the neighbouring point.
+        let p0 = if index == 0 {
+            (2.0f32.mul_add(p1.0, -p2.0), 2.0f32.mul_add(p1.1, -p2.1))
+        } else {
+            self.points[index - 1]
+        };
+        let p3 = if index + 1 == last {
+            (2.0f32.mul_add(p2.0, -p1.0), 2.0f32.mul_add(p2.1, -p1.1))
+        } else {
+            self.points[index + 2]
+        };
+
+        let scale = (1.0 - self.tension) / 2.0;
+
+        [
+            p1,
+            ((p2.0 - p0.0) * scale, (p2.1 - p0.1) * scale),
+            p2,
+            ((p3.0 - p1.0) * scale, (p3.1 - p1.1) * scale),
+        ]
+    }
+
+    fn evaluate(segment: &[(f32, f32); 4], s: f32) -> (f32, f32) {
+        let [(x0, y0), (mx0, my0), (x1, y1), (mx1, my1)] = *segment;
+
+        (hermite(s, x0, mx0, x1, mx1), hermite(s, y0, my0, y1, my1))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn is_single_valued(&self) -> bool {
+        (0..self.points.len() - 1).all(|index| {
+            let segment = self.segment(index);
+
+            (0..=Self::VALIDATION_SAMPLES)
+                .map(|sample| {
+                    Self::evaluate(&segment, sample as f32 / Self::VALIDATION_SAMPLES as f32).0
+                })
+                .collect::<Vec<_>>()
+                .windows(2)
+                .all(|pair| pair[0] < pair[1])
+        })
+    }
+}
+
+impl ParametricCurve<f32> for CatmullRom {
+    fn transform_internal(&self, t: f32) -> f32 {
+        let index = self
+            .points
+            .partition_point(|(x, _)| *x <= t)
+            .clamp(1, self.points.len() - 1)
+            - 1;
+        let segment = self.segment(index);
+
+        let mut start = 0.0;
+        let mut end = 1.0;
+        let mut point = Self::evaluate(&segment, 0.5);
+
+        for _ in 0..Self::SOLVE_ITERATIONS {
+            let midpoint = (start + end) / 2.0;
+
+            point = Self::evaluate(&segment, midpoint);
+
+            if (t - point.0).abs() < Self::SOLVE_ERROR_BOUND {
+                break;
+            }
+
+            if point.0 < t {
+                start = midpoint;
+            } else {
+                end = midpoint;
+            }
+        }
+
+        point.1
+    }
+}
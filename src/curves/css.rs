@@ -0,0 +1,221 @@
+use super::{Curve, Interpolation, Sampled, StepPosition};
+
+impl Curve {
+    /// The CSS `<easing-function>` equivalent to this curve, if there is one.
+    #[must_use]
+    pub fn to_css(&self) -> Option<String> {
+        match self {
+            Self::None => Some(String::from("steps(1, jump-start)")),
+            Self::Linear(_) => Some(String::from("linear")),
+            Self::Cubic(curve) => {
+                let ((a, b), (c, d)) = curve.control_points();
+
+                Some(format!("cubic-bezier({a}, {b}, {c}, {d})"))
+            }
+            Self::Stepped(curve) => Some(format!(
+                "steps({}, {})",
+                curve.step_count(),
+                curve.position().as_str()
+            )),
+            Self::Sampled(curve) if curve.interpolation() == Interpolation::Linear => {
+                Some(format!(
+                    "linear({})",
+                    curve
+                        .points()
+                        .iter()
+                        .map(|(t, y)| format!("{y} {}%", t * 100.0))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a CSS `<easing-function>`: `linear`, the `ease` and `step`
+    /// keywords, `cubic-bezier()`, `steps()` and `linear()`.
+    #[must_use]
+    pub fn from_css(css: &str) -> Option<Self> {
+        let css = css.trim();
+
+        match css {
+            "linear" => return Some(Self::LINEAR),
+            "ease" => return Some(Self::EASE),
+            "ease-in" => return Some(Self::EASE_IN),
+            "ease-out" => return Some(Self::EASE_OUT),
+            "ease-in-out" => return Some(Self::EASE_IN_OUT),
+            "step-start" => return Some(Self::STEP_START),
+            "step-end" => return Some(Self::STEP_END),
+            _ => {}
+        }
+
+        let (function, arguments) = css.strip_suffix(')')?.split_once('(')?;
+        let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+
+        match function.trim() {
+            "cubic-bezier" => {
+                let [a, b, c, d] = arguments[..] else {
+                    return None;
+                };
+                let [a, b, c, d] = [a, b, c, d].map(|argument| argument.parse::<f32>().ok());
+                let (a, b, c, d) = (a?, b?, c?, d?);
+
+                ((0.0..=1.0).contains(&a)
+                    && (0.0..=1.0).contains(&c)
+                    && b.is_finite()
+                    && d.is_finite())
+                .then(|| Self::cubic(a, b, c, d))
+            }
+            "steps" => {
+                let (count, position) = match arguments[..] {
+                    [count] => (count, StepPosition::JumpEnd),
+                    [count, position] => (
+                        count,
+                        match position {
+                            "jump-start" | "start" => StepPosition::JumpStart,
+                            "jump-end" | "end" => StepPosition::JumpEnd,
+                            "jump-none" => StepPosition::JumpNone,
+                            "jump-both" => StepPosition::JumpBoth,
+                            _ => return None,
+                        },
+                    ),
+                    _ => return None,
+                };
+                let count = count.parse::<usize>().ok()?;
+
+                (count > 1 || (count == 1 && position != StepPosition::JumpNone))
+                    .then(|| Self::steps(count, position))
+            }
+            "linear" => Self::parse_linear_stops(&arguments),
+            _ => None,
+        }
+    }
+
+    // https://drafts.csswg.org/css-easing-2/#linear-easing-function-parsing
+    fn parse_linear_stops(arguments: &[&str]) -> Option<Self> {
+        let mut stops: Vec<(Option<f32>, f32)> = Vec::new();
+
+        for argument in arguments {
+            let mut parts = argument.split_whitespace();
+            let value = parts.next()?.parse::<f32>().ok()?;
+            let inputs = parts
+                .map(|part| Some(part.strip_suffix('%')?.parse::<f32>().ok()? / 100.0))
+                .collect::<Option<Vec<_>>>()?;
+
+            match inputs[..] {
+                [] => stops.push((None, value)),
+                [input] => stops.push((Some(input), value)),
+                [start, end] => stops.extend([(Some(start), value), (Some(end), value)]),
+                _ => return None,
+            }
+        }
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        let last = stops.len() - 1;
+
+        stops[0].0.get_or_insert(0.0);
+        stops[last].0.get_or_insert(1.0);
+
+        // Inputs can't go backwards.
+        let mut largest = f32::NEG_INFINITY;
+
+        for (input, _) in &mut stops {
+            if let Some(input) = input {
+                *input = input.max(largest);
+                largest = *input;
+            }
+        }
+
+        // Missing inputs are spread evenly between the surrounding ones.
+        let mut index = 0;
+
+        while index < last {
+            if stops[index + 1].0.is_some() {
+                index += 1;
+
+                continue;
+            }
+
+            let end = (index + 1..=last).find(|end| stops[*end].0.is_some())?;
+            let (from, to) = (stops[index].0?, stops[end].0?);
+
+            #[allow(clippy::cast_precision_loss)]
+            for (offset, stop) in stops[index + 1..end].iter_mut().enumerate() {
+                let progress = (offset + 1) as f32 / (end - index) as f32;
+
+                stop.0 = Some((to - from).mul_add(progress, from));
+            }
+
+            index = end;
+        }
+
+        Sampled::try_new(
+            stops
+                .into_iter()
+                .map(|(input, value)| (input.unwrap_or_default(), value)),
+            Interpolation::Linear,
+        )
+        .ok()
+        .map(Self::Sampled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::{Curve, ICurve, Interpolation, StepPosition};
+
+    #[test]
+    fn keywords() {
+        assert!(Curve::from_css("ease-in-out") == Some(Curve::EASE_IN_OUT));
+        assert!(Curve::from_css(" step-end ") == Some(Curve::STEP_END));
+        assert!(Curve::from_css("bounce").is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        for curve in [
+            Curve::LINEAR,
+            Curve::EASE_IN_OUT_BACK,
+            Curve::steps(3, StepPosition::JumpBoth),
+            Curve::sampled([(0.0, 0.0), (0.25, 0.5), (1.0, 1.0)], Interpolation::Linear),
+        ] {
+            let css = curve.to_css().unwrap();
+
+            assert!(Curve::from_css(&css) == Some(curve), "{css}");
+        }
+
+        let none = Curve::from_css(&Curve::NONE.to_css().unwrap()).unwrap();
+
+        assert_eq!(none.transform(0.5), Curve::NONE.transform(0.5));
+
+        assert_eq!(
+            Curve::from_css("cubic-bezier(0.1, 0.7, 1, 0.1)")
+                .unwrap()
+                .to_css()
+                .as_deref(),
+            Some("cubic-bezier(0.1, 0.7, 1, 0.1)")
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(Curve::from_css("cubic-bezier(1.5, 0, 0, 1)").is_none());
+        assert!(Curve::from_css("cubic-bezier(0, 0, 1)").is_none());
+        assert!(Curve::from_css("steps(1, jump-none)").is_none());
+        assert!(Curve::from_css("steps(0)").is_none());
+        assert!(Curve::from_css("linear(0)").is_none());
+    }
+
+    #[test]
+    fn linear_stops() {
+        let curve = Curve::from_css("linear(0, 0.5 25% 75%, 1)").unwrap();
+
+        assert_eq!(
+            curve.to_css().as_deref(),
+            Some("linear(0 0%, 0.5 25%, 0.5 75%, 1 100%)")
+        );
+    }
+}
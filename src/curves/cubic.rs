@@ -1,43 +1,196 @@
 use super::ParametricCurve;
+use std::time::Duration;
 
-#[derive(Clone, PartialEq)]
+/// A cubic Bézier going from `(0.0, 0.0)` to `(1.0, 1.0)` with the control
+/// points `(a, b)` and `(c, d)`, like the CSS `cubic-bezier()` easing function.
+///
+/// The polynomial coefficients and a table of samples along the x axis are
+/// computed once at construction, so solving for `t` is a Newton-Raphson
+/// refinement of a good initial guess with a bisection fallback.
+#[derive(Clone, Copy, PartialEq)]
 pub struct Cubic {
     pub(super) a: f32,
     pub(super) b: f32,
     pub(super) c: f32,
     pub(super) d: f32,
+    ax: f64,
+    bx: f64,
+    cx: f64,
+    ay: f64,
+    by: f64,
+    cy: f64,
+    samples: [f64; Self::SPLINE_SAMPLES],
 }
 
 impl Cubic {
     pub const CUBIC_ERROR_BOUND: f32 = 0.001;
 
+    /// Precision of the solver on the x axis when the duration of the
+    /// animation isn't known.
+    pub(super) const SOLVER_ERROR_BOUND: f32 = 1e-6;
+
+    const SPLINE_SAMPLES: usize = 11;
+    const MAX_NEWTON_ITERATIONS: usize = 4;
+    const MAX_BISECTION_ITERATIONS: usize = 64;
+    const MIN_SLOPE: f64 = 1e-6;
+
+    #[must_use]
     pub const fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
-        Self { a, b, c, d }
+        let (x1, y1, x2, y2) = (a as f64, b as f64, c as f64, d as f64);
+
+        let cx = 3.0 * x1;
+        let bx = 3.0 * (x2 - x1) - cx;
+        let ax = 1.0 - cx - bx;
+
+        let cy = 3.0 * y1;
+        let by = 3.0 * (y2 - y1) - cy;
+        let ay = 1.0 - cy - by;
+
+        let mut samples = [0.0; Self::SPLINE_SAMPLES];
+        let mut index = 0;
+
+        while index < Self::SPLINE_SAMPLES {
+            let s = index as f64 / (Self::SPLINE_SAMPLES - 1) as f64;
+
+            samples[index] = ((ax * s + bx) * s + cx) * s;
+            index += 1;
+        }
+
+        Self {
+            a,
+            b,
+            c,
+            d,
+            ax,
+            bx,
+            cx,
+            ay,
+            by,
+            cy,
+            samples,
+        }
     }
 
-    fn _evaluate_cubic(a: f32, b: f32, m: f32) -> f32 {
-        (3.0 * a * (1.0 - m).powi(2)).mul_add(m, 3.0 * b * (1.0 - m) * m.powi(2)) + m.powi(3)
+    /// The two control points, `(a, b)` and `(c, d)`.
+    #[must_use]
+    pub const fn control_points(&self) -> ((f32, f32), (f32, f32)) {
+        ((self.a, self.b), (self.c, self.d))
     }
-}
 
-impl ParametricCurve<f32> for Cubic {
-    fn transform_internal(&self, t: f32) -> f32 {
+    /// The solver precision needed for an animation lasting `duration`, as in
+    /// WebKit: the longer the animation, the more precise the solution has to
+    /// be so that consecutive frames stay smooth.
+    #[must_use]
+    pub fn error_bound_for_duration(duration: Duration) -> f32 {
+        if !duration.is_zero() {
+            (1.0 / (200.0 * duration.as_secs_f32())).min(1e-3)
+        } else {
+            1e-3
+        }
+    }
+
+    fn sample_x(&self, s: f64) -> f64 {
+        self.ax.mul_add(s, self.bx).mul_add(s, self.cx) * s
+    }
+
+    fn sample_y(&self, s: f64) -> f64 {
+        self.ay.mul_add(s, self.by).mul_add(s, self.cy) * s
+    }
+
+    fn sample_derivative_y(&self, s: f64) -> f64 {
+        (3.0 * self.ay)
+            .mul_add(s, 2.0 * self.by)
+            .mul_add(s, self.cy)
+    }
+
+    fn sample_derivative_x(&self, s: f64) -> f64 {
+        (3.0 * self.ax)
+            .mul_add(s, 2.0 * self.bx)
+            .mul_add(s, self.cx)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn solve_x(&self, x: f64, epsilon: f64) -> f64 {
+        // Linearly interpolate between the two closest samples for the initial guess.
+        let step = 1.0 / (Self::SPLINE_SAMPLES - 1) as f64;
+        let index = self
+            .samples
+            .partition_point(|sample| *sample <= x)
+            .clamp(1, Self::SPLINE_SAMPLES - 1)
+            - 1;
+        let (start, end) = (self.samples[index], self.samples[index + 1]);
+
+        let mut s = index as f64 * step;
+
+        if end > start {
+            s += (x - start) / (end - start) * step;
+        }
+
+        for _ in 0..Self::MAX_NEWTON_ITERATIONS {
+            let error = self.sample_x(s) - x;
+
+            if error.abs() < epsilon {
+                return s;
+            }
+
+            let slope = self.sample_derivative_x(s);
+
+            if slope.abs() < Self::MIN_SLOPE {
+                break;
+            }
+
+            s -= error / slope;
+        }
+
         let mut start = 0.0;
         let mut end = 1.0;
 
-        loop {
-            let midpoint = (start + end) / 2.0;
-            let estimate = Self::_evaluate_cubic(self.a, self.c, midpoint);
+        s = x.clamp(0.0, 1.0);
 
-            if (t - estimate).abs() < Self::CUBIC_ERROR_BOUND {
-                return Self::_evaluate_cubic(self.b, self.d, midpoint);
+        for _ in 0..Self::MAX_BISECTION_ITERATIONS {
+            let estimate = self.sample_x(s);
+
+            if (estimate - x).abs() < epsilon {
+                break;
             }
 
-            if estimate < t {
-                start = midpoint;
+            if estimate < x {
+                start = s;
             } else {
-                end = midpoint;
+                end = s;
             }
+
+            s = (end - start).mul_add(0.5, start);
         }
+
+        s
+    }
+
+    /// Evaluates the curve at `t`, solving for it on the x axis with the given
+    /// precision.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn solve(&self, t: f32, error_bound: f32) -> f32 {
+        self.sample_y(self.solve_x(f64::from(t), f64::from(error_bound))) as f32
+    }
+
+    /// The slope of the curve at `t`, or `None` where it is vertical.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn slope(&self, t: f32) -> Option<f32> {
+        let s = self.solve_x(f64::from(t), f64::from(Self::SOLVER_ERROR_BOUND));
+        let dx = self.sample_derivative_x(s);
+
+        (dx.abs() >= Self::MIN_SLOPE).then(|| (self.sample_derivative_y(s) / dx) as f32)
+    }
+}
+
+impl ParametricCurve<f32> for Cubic {
+    fn transform_internal(&self, t: f32) -> f32 {
+        self.solve(t, Self::SOLVER_ERROR_BOUND)
+    }
+
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        self.slope(t)
     }
 }
@@ -9,4 +9,8 @@ impl ParametricCurve<f32> for DecelerateCurve {
 
         t.mul_add(-t, 1.0)
     }
+
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        Some(2.0 * (1.0 - t))
+    }
 }
@@ -2,72 +2,132 @@ use std::f32::consts::PI;
 
 use super::ParametricCurve;
 
+/// Returns the amplitude actually used by an elastic curve and the phase shift
+/// needed for it to start at `0.0` and end at `1.0`.
+fn _phase(period: f32, amplitude: f32) -> (f32, f32) {
+    if amplitude < 1.0 {
+        (1.0, period / 4.0)
+    } else {
+        (amplitude, period / (PI * 2.0) * (1.0 / amplitude).asin())
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct ElasticInCurve {
     period: f32,
+    amplitude: f32,
 }
 
 impl ElasticInCurve {
+    /// Amplitudes below `1.0` are treated as `1.0`.
+    #[must_use]
+    pub const fn new(period: f32, amplitude: f32) -> Self {
+        Self { period, amplitude }
+    }
+
     #[must_use]
     pub const fn default() -> Self {
-        Self { period: 0.4 }
+        Self::new(0.4, 1.0)
+    }
+
+    #[must_use]
+    pub const fn period(&self) -> f32 {
+        self.period
+    }
+
+    #[must_use]
+    pub const fn amplitude(&self) -> f32 {
+        self.amplitude
     }
 }
 
 impl ParametricCurve<f32> for ElasticInCurve {
     fn transform_internal(&self, mut t: f32) -> f32 {
-        let s = self.period / 4.0;
+        let (amplitude, s) = _phase(self.period, self.amplitude);
 
         t -= 1.0;
 
-        -(10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).cos()
+        -(amplitude * (10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).sin())
     }
 }
 
 #[derive(Clone, PartialEq)]
 pub struct ElasticOutCurve {
     period: f32,
+    amplitude: f32,
 }
 
 impl ElasticOutCurve {
+    /// Amplitudes below `1.0` are treated as `1.0`.
+    #[must_use]
+    pub const fn new(period: f32, amplitude: f32) -> Self {
+        Self { period, amplitude }
+    }
+
     #[must_use]
     pub const fn default() -> Self {
-        Self { period: 0.4 }
+        Self::new(0.4, 1.0)
+    }
+
+    #[must_use]
+    pub const fn period(&self) -> f32 {
+        self.period
+    }
+
+    #[must_use]
+    pub const fn amplitude(&self) -> f32 {
+        self.amplitude
     }
 }
 
 impl ParametricCurve<f32> for ElasticOutCurve {
     fn transform_internal(&self, t: f32) -> f32 {
-        let s = self.period / 4.0;
+        let (amplitude, s) = _phase(self.period, self.amplitude);
 
-        (-10.0 * t)
-            .exp2()
-            .mul_add(((t - s) * (PI * 2.0) / self.period).sin(), 1.0)
+        (amplitude * (-10.0 * t).exp2()).mul_add(((t - s) * (PI * 2.0) / self.period).sin(), 1.0)
     }
 }
 
 #[derive(Clone, PartialEq)]
 pub struct ElasticInOutCurve {
     period: f32,
+    amplitude: f32,
 }
 
 impl ElasticInOutCurve {
+    /// Amplitudes below `1.0` are treated as `1.0`.
+    #[must_use]
+    pub const fn new(period: f32, amplitude: f32) -> Self {
+        Self { period, amplitude }
+    }
+
     #[must_use]
     pub const fn default() -> Self {
-        Self { period: 0.4 }
+        Self::new(0.4, 1.0)
+    }
+
+    #[must_use]
+    pub const fn period(&self) -> f32 {
+        self.period
+    }
+
+    #[must_use]
+    pub const fn amplitude(&self) -> f32 {
+        self.amplitude
     }
 }
 
 impl ParametricCurve<f32> for ElasticInOutCurve {
     fn transform_internal(&self, mut t: f32) -> f32 {
-        let s = self.period / 4.0;
+        let (amplitude, s) = _phase(self.period, self.amplitude);
 
         t = 2.0f32.mul_add(t, -1.0);
 
         if t < 0.0 {
-            -0.5 * (10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).sin()
+            -0.5 * amplitude * (10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).sin()
         } else {
-            ((-10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).sin()).mul_add(0.5, 1.0)
+            (amplitude * (-10.0 * t).exp2() * ((t - s) * (PI * 2.0) / self.period).sin())
+                .mul_add(0.5, 1.0)
         }
     }
 }
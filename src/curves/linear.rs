@@ -7,4 +7,8 @@ impl ParametricCurve<f32> for Linear {
     fn transform_internal(&self, t: f32) -> f32 {
         t
     }
+
+    fn derivative_internal(&self, _: f32) -> Option<f32> {
+        Some(1.0)
+    }
 }
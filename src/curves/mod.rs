@@ -1,32 +1,51 @@
+mod back;
 mod bounce;
+mod catmull_rom;
+mod css;
 mod cubic;
 mod decelerate;
 mod elastic;
 mod interval;
 mod linear;
+mod sampled;
 mod saw_tooth;
 mod split;
 mod step;
 mod three_point_cubic;
 mod threshold;
 
+use std::time::Duration;
 use three_point_cubic::ThreePointCubic;
 
 pub use self::{
+    back::{BackInCurve, BackInOutCurve, BackOutCurve},
     bounce::{BounceInCurve, BounceInOutCurve, BounceOutCurve},
+    catmull_rom::CatmullRom,
     cubic::Cubic,
     decelerate::DecelerateCurve,
     elastic::{ElasticInCurve, ElasticInOutCurve, ElasticOutCurve},
     interval::Interval,
     linear::Linear,
+    sampled::{Interpolation, Sampled},
     saw_tooth::SawTooth,
     split::Split,
-    step::Stepped,
+    step::{StepPosition, Stepped},
     threshold::Threshold,
 };
 
 trait ParametricCurve<T> {
     fn transform_internal(&self, t: f32) -> T;
+
+    /// Whether `0.0` and `1.0` are always mapped onto themselves, which lets
+    /// [`ICurve::transform`] skip evaluating the curve at its ends.
+    fn preserves_endpoints(&self) -> bool {
+        true
+    }
+
+    /// The analytic derivative of the curve at `t`, if it is known.
+    fn derivative_internal(&self, _t: f32) -> Option<f32> {
+        None
+    }
 }
 
 pub trait ICurve {
@@ -39,7 +58,7 @@ impl<T: ParametricCurve<f32>> ICurve for T {
         Self: Sized,
     {
         match t {
-            0.0 | 1.0 => t,
+            0.0 | 1.0 if self.preserves_endpoints() => t,
             t => self.transform_internal(t),
         }
     }
@@ -53,6 +72,9 @@ pub enum Curve {
     ThreePointCubic(ThreePointCubic),
     Threshold(Threshold),
     SawTooth(SawTooth),
+    BackIn(BackInCurve),
+    BackOut(BackOutCurve),
+    BackInOut(BackInOutCurve),
     BounceIn(BounceInCurve),
     BounceOut(BounceOutCurve),
     BounceInOut(BounceInOutCurve),
@@ -61,9 +83,16 @@ pub enum Curve {
     ElasticInOut(ElasticInOutCurve),
     Decelerate(DecelerateCurve),
     Stepped(Stepped),
+    Sampled(Sampled),
+    CatmullRom(CatmullRom),
 }
 
 impl Curve {
+    const DIFFERENTIATION_STEP: f32 = 1e-3;
+    const MONOTONICITY_SAMPLES: usize = 256;
+    const INVERSE_ITERATIONS: usize = 32;
+    const INVERSE_ERROR_BOUND: f32 = 1e-4;
+
     pub const NONE: Self = Self::None;
     pub const LINEAR: Self = Self::Linear(Linear);
     pub const DECELERATE: Self = Self::Decelerate(DecelerateCurve);
@@ -114,12 +143,97 @@ impl Curve {
     pub const EASE_IN_OUT_BACK: Self = Self::cubic(0.68, -0.55, 0.265, 1.55);
     pub const FAST_OUT_SLOW_IN: Self = Self::cubic(0.4, 0.0, 0.2, 1.0);
     pub const SLOW_MIDDLE: Self = Self::cubic(0.15, 0.85, 0.85, 0.15);
-    pub const BOUNCE_IN: Self = Self::BounceIn(BounceInCurve);
-    pub const BOUNCE_OUT: Self = Self::BounceOut(BounceOutCurve);
-    pub const BOUNCE_IN_OUT: Self = Self::BounceInOut(BounceInOutCurve);
+    pub const BACK_IN: Self = Self::BackIn(BackInCurve::default());
+    pub const BACK_OUT: Self = Self::BackOut(BackOutCurve::default());
+    pub const BACK_IN_OUT: Self = Self::BackInOut(BackInOutCurve::default());
+    pub const BOUNCE_IN: Self = Self::BounceIn(BounceInCurve::default());
+    pub const BOUNCE_OUT: Self = Self::BounceOut(BounceOutCurve::default());
+    pub const BOUNCE_IN_OUT: Self = Self::BounceInOut(BounceInOutCurve::default());
     pub const ELASTIC_IN: Self = Self::ElasticIn(ElasticInCurve::default());
     pub const ELASTIC_OUT: Self = Self::ElasticOut(ElasticOutCurve::default());
     pub const ELASTIC_IN_OUT: Self = Self::ElasticInOut(ElasticInOutCurve::default());
+    pub const STEP_START: Self = Self::steps(1, StepPosition::JumpStart);
+    pub const STEP_END: Self = Self::steps(1, StepPosition::JumpEnd);
+
+    /// Every preset above with its name, in kebab-case like the CSS keywords.
+    pub const PRESETS: &'static [(&'static str, Self)] = &[
+        ("none", Self::NONE),
+        ("linear", Self::LINEAR),
+        ("decelerate", Self::DECELERATE),
+        (
+            "fast-linear-to-slow-ease-in",
+            Self::FAST_LINEAR_TO_SLOW_EASE_IN,
+        ),
+        (
+            "fast-ease-in-to-slow-ease-out",
+            Self::FAST_EASE_IN_TO_SLOW_EASE_OUT,
+        ),
+        ("ease", Self::EASE),
+        ("ease-in", Self::EASE_IN),
+        ("ease-in-to-linear", Self::EASE_IN_TO_LINEAR),
+        ("ease-in-sine", Self::EASE_IN_SINE),
+        ("ease-in-quad", Self::EASE_IN_QUAD),
+        ("ease-in-cubic", Self::EASE_IN_CUBIC),
+        ("ease-in-quart", Self::EASE_IN_QUART),
+        ("ease-in-quint", Self::EASE_IN_QUINT),
+        ("ease-in-expo", Self::EASE_IN_EXPO),
+        ("ease-in-circ", Self::EASE_IN_CIRC),
+        ("ease-in-back", Self::EASE_IN_BACK),
+        ("ease-out", Self::EASE_OUT),
+        ("linear-to-ease-out", Self::LINEAR_TO_EASE_OUT),
+        ("ease-out-sine", Self::EASE_OUT_SINE),
+        ("ease-out-quad", Self::EASE_OUT_QUAD),
+        ("ease-out-cubic", Self::EASE_OUT_CUBIC),
+        ("ease-out-quart", Self::EASE_OUT_QUART),
+        ("ease-out-quint", Self::EASE_OUT_QUINT),
+        ("ease-out-expo", Self::EASE_OUT_EXPO),
+        ("ease-out-circ", Self::EASE_OUT_CIRC),
+        ("ease-out-back", Self::EASE_OUT_BACK),
+        ("ease-in-out", Self::EASE_IN_OUT),
+        ("ease-in-out-sine", Self::EASE_IN_OUT_SINE),
+        ("ease-in-out-quad", Self::EASE_IN_OUT_QUAD),
+        ("ease-in-out-cubic", Self::EASE_IN_OUT_CUBIC),
+        (
+            "ease-in-out-cubic-emphasized",
+            Self::EASE_IN_OUT_CUBIC_EMPHASIZED,
+        ),
+        ("ease-in-out-quart", Self::EASE_IN_OUT_QUART),
+        ("ease-in-out-quint", Self::EASE_IN_OUT_QUINT),
+        ("ease-in-out-expo", Self::EASE_IN_OUT_EXPO),
+        ("ease-in-out-circ", Self::EASE_IN_OUT_CIRC),
+        ("ease-in-out-back", Self::EASE_IN_OUT_BACK),
+        ("fast-out-slow-in", Self::FAST_OUT_SLOW_IN),
+        ("slow-middle", Self::SLOW_MIDDLE),
+        ("back-in", Self::BACK_IN),
+        ("back-out", Self::BACK_OUT),
+        ("back-in-out", Self::BACK_IN_OUT),
+        ("bounce-in", Self::BOUNCE_IN),
+        ("bounce-out", Self::BOUNCE_OUT),
+        ("bounce-in-out", Self::BOUNCE_IN_OUT),
+        ("elastic-in", Self::ELASTIC_IN),
+        ("elastic-out", Self::ELASTIC_OUT),
+        ("elastic-in-out", Self::ELASTIC_IN_OUT),
+        ("step-start", Self::STEP_START),
+        ("step-end", Self::STEP_END),
+    ];
+
+    /// Looks up a preset by its name, see [`Curve::PRESETS`].
+    #[must_use]
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::PRESETS
+            .iter()
+            .find(|(preset, _)| *preset == name)
+            .map(|(_, curve)| curve.clone())
+    }
+
+    /// The name of the preset equal to this curve, if there is one.
+    #[must_use]
+    pub fn preset_name(&self) -> Option<&'static str> {
+        Self::PRESETS
+            .iter()
+            .find(|(_, curve)| curve == self)
+            .map(|(name, _)| *name)
+    }
 
     #[must_use]
     pub const fn three_point_cubic(
@@ -148,16 +262,182 @@ impl Curve {
     }
 
     #[must_use]
+    pub const fn back_in(overshoot: f32) -> Self {
+        Self::BackIn(BackInCurve::new(overshoot))
+    }
+
+    #[must_use]
+    pub const fn back_out(overshoot: f32) -> Self {
+        Self::BackOut(BackOutCurve::new(overshoot))
+    }
+
+    #[must_use]
+    pub const fn back_in_out(overshoot: f32) -> Self {
+        Self::BackInOut(BackInOutCurve::new(overshoot))
+    }
+
+    #[must_use]
+    pub const fn bounce_in(bounces: usize, restitution: f32) -> Self {
+        Self::BounceIn(BounceInCurve::new(bounces, restitution))
+    }
+
+    #[must_use]
+    pub const fn bounce_out(bounces: usize, restitution: f32) -> Self {
+        Self::BounceOut(BounceOutCurve::new(bounces, restitution))
+    }
+
+    #[must_use]
+    pub const fn bounce_in_out(bounces: usize, restitution: f32) -> Self {
+        Self::BounceInOut(BounceInOutCurve::new(bounces, restitution))
+    }
+
+    #[must_use]
+    pub const fn elastic_in(period: f32, amplitude: f32) -> Self {
+        Self::ElasticIn(ElasticInCurve::new(period, amplitude))
+    }
+
+    #[must_use]
+    pub const fn elastic_out(period: f32, amplitude: f32) -> Self {
+        Self::ElasticOut(ElasticOutCurve::new(period, amplitude))
+    }
+
+    #[must_use]
+    pub const fn elastic_in_out(period: f32, amplitude: f32) -> Self {
+        Self::ElasticInOut(ElasticInOutCurve::new(period, amplitude))
+    }
+
+    /// Like the CSS `steps()` easing function.
+    #[must_use]
+    pub const fn steps(step_count: usize, position: StepPosition) -> Self {
+        Self::Stepped(Stepped::new(step_count, position))
+    }
+
+    /// Maps the former single frame flags onto a [`StepPosition`]: a single
+    /// initial frame is `jump-start`, a single final frame is `jump-end`, and
+    /// neither, which rounded to the nearest step, is `jump-none` with one
+    /// more step.
+    #[must_use]
+    #[deprecated(note = "use `Curve::steps` with a `StepPosition`")]
     pub const fn stepped(
         step_count: usize,
         is_initial_step_single_frame: bool,
         is_final_step_single_frame: bool,
     ) -> Self {
-        Self::Stepped(Stepped {
-            is_initial_step_single_frame,
-            is_final_step_single_frame,
-            step_count,
-        })
+        if is_initial_step_single_frame {
+            Self::steps(step_count, StepPosition::JumpStart)
+        } else if is_final_step_single_frame {
+            Self::steps(step_count, StepPosition::JumpEnd)
+        } else {
+            Self::steps(step_count + 1, StepPosition::JumpNone)
+        }
+    }
+
+    /// Like [`ICurve::transform`], but lets the Bézier based curves derive
+    /// their solver precision from the duration of the animation instead of
+    /// using a fixed one.
+    #[must_use]
+    pub fn transform_for_duration(&self, t: f32, duration: Duration) -> f32 {
+        match self {
+            Self::Cubic(curve) if !matches!(t, 0.0 | 1.0) => {
+                curve.solve(t, Cubic::error_bound_for_duration(duration))
+            }
+            Self::ThreePointCubic(curve) if !matches!(t, 0.0 | 1.0) => {
+                curve.solve(t, Cubic::error_bound_for_duration(duration))
+            }
+            curve => curve.transform(t),
+        }
+    }
+
+    /// The rate of change of the curve at `t`, analytic where the derivative
+    /// is known and numerically differentiated otherwise.
+    #[must_use]
+    pub fn velocity(&self, t: f32) -> f32 {
+        if let Some(velocity) = self.derivative_internal(t) {
+            return velocity;
+        }
+
+        let (start, end) = (
+            (t - Self::DIFFERENTIATION_STEP).max(0.0),
+            (t + Self::DIFFERENTIATION_STEP).min(1.0),
+        );
+
+        (self.transform(end) - self.transform(start)) / (end - start)
+    }
+
+    /// Whether the curve never changes direction, checked by sampling it.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn is_monotonic(&self) -> bool {
+        let samples: Vec<f32> = (0..=Self::MONOTONICITY_SAMPLES)
+            .map(|sample| self.transform(sample as f32 / Self::MONOTONICITY_SAMPLES as f32))
+            .collect();
+
+        samples.windows(2).all(|pair| pair[0] <= pair[1])
+            || samples.windows(2).all(|pair| pair[0] >= pair[1])
+    }
+
+    /// Finds the `t` at which the curve reaches `y`.
+    ///
+    /// Returns `None` if the curve turns out not to be monotonic inside the
+    /// searched range, or if it never reaches `y` (`y` is out of range, or
+    /// falls between two steps).
+    #[must_use]
+    pub fn inverse(&self, y: f32) -> Option<f32> {
+        let mut start = (0.0f32, self.transform(0.0));
+        let mut end = (1.0f32, self.transform(1.0));
+        let is_increasing = end.1 >= start.1;
+
+        for _ in 0..Self::INVERSE_ITERATIONS {
+            let midpoint = (start.0 + end.0) / 2.0;
+            let estimate = self.transform(midpoint);
+
+            if !(start.1.min(end.1)..=start.1.max(end.1)).contains(&estimate) {
+                return None;
+            }
+
+            if (estimate < y) == is_increasing {
+                start = (midpoint, estimate);
+            } else {
+                end = (midpoint, estimate);
+            }
+        }
+
+        [start, end]
+            .into_iter()
+            .find(|(_, estimate)| (estimate - y).abs() < Self::INVERSE_ERROR_BOUND)
+            .map(|(t, _)| t)
+    }
+
+    /// Builds a curve from a table of `(t, y)` points, see [`Sampled::new`].
+    #[must_use]
+    pub fn sampled<P: IntoIterator<Item = (f32, f32)>>(
+        points: P,
+        interpolation: Interpolation,
+    ) -> Self {
+        Self::Sampled(Sampled::new(points, interpolation))
+    }
+
+    /// Builds a Catmull-Rom spline through the given control points, see
+    /// [`CatmullRom::new`].
+    #[must_use]
+    pub fn catmull_rom<P: IntoIterator<Item = (f32, f32)>>(
+        control_points: P,
+        tension: f32,
+    ) -> Self {
+        Self::CatmullRom(CatmullRom::new(control_points, tension))
+    }
+
+    /// Builds a monotone cubic spline going from `(0.0, 0.0)` to `(1.0, 1.0)`
+    /// through the given control points, which have to be strictly increasing
+    /// in `t`.
+    #[must_use]
+    pub fn monotone_spline<P: IntoIterator<Item = (f32, f32)>>(control_points: P) -> Self {
+        Self::sampled(
+            std::iter::once((0.0, 0.0))
+                .chain(control_points)
+                .chain(std::iter::once((1.0, 1.0))),
+            Interpolation::MonotoneCubic,
+        )
     }
 }
 
@@ -170,6 +450,9 @@ impl ParametricCurve<f32> for Curve {
             Self::ThreePointCubic(curve) => curve.transform_internal(t),
             Self::Threshold(curve) => curve.transform_internal(t),
             Self::SawTooth(curve) => curve.transform_internal(t),
+            Self::BackIn(curve) => curve.transform_internal(t),
+            Self::BackOut(curve) => curve.transform_internal(t),
+            Self::BackInOut(curve) => curve.transform_internal(t),
             Self::BounceIn(curve) => curve.transform_internal(t),
             Self::BounceOut(curve) => curve.transform_internal(t),
             Self::BounceInOut(curve) => curve.transform_internal(t),
@@ -178,6 +461,87 @@ impl ParametricCurve<f32> for Curve {
             Self::ElasticInOut(curve) => curve.transform_internal(t),
             Self::Decelerate(curve) => curve.transform_internal(t),
             Self::Stepped(curve) => curve.transform_internal(t),
+            Self::Sampled(curve) => curve.transform_internal(t),
+            Self::CatmullRom(curve) => curve.transform_internal(t),
         }
     }
+
+    fn preserves_endpoints(&self) -> bool {
+        match self {
+            Self::Stepped(curve) => curve.preserves_endpoints(),
+            Self::Sampled(curve) => curve.preserves_endpoints(),
+            _ => true,
+        }
+    }
+
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        match self {
+            Self::None => Some(0.0),
+            Self::Linear(curve) => curve.derivative_internal(t),
+            Self::Cubic(curve) => curve.derivative_internal(t),
+            Self::ThreePointCubic(curve) => curve.derivative_internal(t),
+            Self::Threshold(curve) => curve.derivative_internal(t),
+            Self::SawTooth(curve) => curve.derivative_internal(t),
+            Self::BackIn(curve) => curve.derivative_internal(t),
+            Self::BackOut(curve) => curve.derivative_internal(t),
+            Self::BackInOut(curve) => curve.derivative_internal(t),
+            Self::BounceIn(curve) => curve.derivative_internal(t),
+            Self::BounceOut(curve) => curve.derivative_internal(t),
+            Self::BounceInOut(curve) => curve.derivative_internal(t),
+            Self::ElasticIn(curve) => curve.derivative_internal(t),
+            Self::ElasticOut(curve) => curve.derivative_internal(t),
+            Self::ElasticInOut(curve) => curve.derivative_internal(t),
+            Self::Decelerate(curve) => curve.derivative_internal(t),
+            Self::Stepped(curve) => curve.derivative_internal(t),
+            Self::Sampled(curve) => curve.derivative_internal(t),
+            Self::CatmullRom(curve) => curve.derivative_internal(t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Curve, ICurve};
+
+    #[test]
+    fn velocity_matches_the_slope() {
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            assert!((Curve::LINEAR.velocity(t) - 1.0).abs() < 1e-4, "at t = {t}");
+        }
+
+        let curve = Curve::EASE_IN_OUT;
+        let numeric = (curve.transform(0.501) - curve.transform(0.499)) / 0.002;
+
+        assert!((curve.velocity(0.5) - numeric).abs() < 1e-2);
+        assert!(curve.velocity(0.5) > 1.0);
+        assert!(curve.velocity(0.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn monotonic_presets() {
+        assert!(Curve::LINEAR.is_monotonic());
+        assert!(Curve::EASE.is_monotonic());
+        assert!(Curve::FAST_EASE_IN_TO_SLOW_EASE_OUT.is_monotonic());
+        assert!(Curve::STEP_END.is_monotonic());
+        assert!(!Curve::EASE_IN_OUT_BACK.is_monotonic());
+        assert!(!Curve::EASE_OUT_BACK.is_monotonic());
+    }
+
+    #[test]
+    fn inverse_of_presets() {
+        assert!((Curve::LINEAR.inverse(0.3).unwrap() - 0.3).abs() < 1e-4);
+
+        for t in [0.1, 0.4, 0.9] {
+            let y = Curve::EASE.transform(t);
+
+            assert!(
+                (Curve::EASE.inverse(y).unwrap() - t).abs() < 1e-3,
+                "at t = {t}"
+            );
+        }
+
+        assert_eq!(Curve::EASE.inverse(1.5), None);
+        assert_eq!(Curve::STEP_END.inverse(0.3), None);
+        assert_eq!(Curve::EASE_IN_OUT_BACK.inverse(-0.05), None);
+    }
 }
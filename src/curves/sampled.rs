@@ -0,0 +1,243 @@
+use super::ParametricCurve;
+
+/// How a [`Sampled`] curve fills the gaps between its points.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight lines between points, like the CSS `linear()` easing function.
+    #[default]
+    Linear,
+    /// A Fritsch-Carlson monotone cubic, smooth and without overshooting the
+    /// sampled values.
+    MonotoneCubic,
+}
+
+/// A curve built from a table of `(t, y)` points, such as easing exported
+/// from motion design tools.
+///
+/// Inputs outside of the sampled range are clamped to the first and last
+/// points.
+#[derive(Clone, PartialEq)]
+pub struct Sampled {
+    points: Vec<(f32, f32)>,
+    tangents: Vec<f32>,
+    interpolation: Interpolation,
+}
+
+impl Sampled {
+    /// # Panics
+    ///
+    /// Panics if the points are invalid, see [`Sampled::try_new`].
+    #[must_use]
+    pub fn new<P: IntoIterator<Item = (f32, f32)>>(
+        points: P,
+        interpolation: Interpolation,
+    ) -> Self {
+        Self::try_new(points, interpolation).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Builds the curve, or explains why the points can't make one: there has
+    /// to be at least two of them, all finite, and `t` has to be increasing.
+    ///
+    /// Linear interpolation allows several points at the same `t`, which makes
+    /// the curve jump from one value to the next like CSS `linear()` does,
+    /// while monotone cubic interpolation needs `t` to be strictly increasing.
+    pub fn try_new<P: IntoIterator<Item = (f32, f32)>>(
+        points: P,
+        interpolation: Interpolation,
+    ) -> Result<Self, &'static str> {
+        let points: Vec<_> = points.into_iter().collect();
+
+        if points.len() < 2 {
+            return Err("expected at least two points");
+        }
+
+        if !points.iter().all(|(t, y)| t.is_finite() && y.is_finite()) {
+            return Err("expected finite points");
+        }
+
+        let tangents = match interpolation {
+            Interpolation::Linear => {
+                if !points.windows(2).all(|pair| pair[0].0 <= pair[1].0) {
+                    return Err("expected t to be increasing");
+                }
+
+                Vec::new()
+            }
+            Interpolation::MonotoneCubic => {
+                if !points.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+                    return Err("expected t to be strictly increasing");
+                }
+
+                Self::monotone_tangents(&points)
+            }
+        };
+
+        Ok(Self {
+            points,
+            tangents,
+            interpolation,
+        })
+    }
+
+    #[must_use]
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+
+    #[must_use]
+    pub const fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the index of the point ending the segment containing `t`.
+    fn segment_end(&self, t: f32) -> usize {
+        self.points
+            .partition_point(|(x, _)| *x <= t)
+            .clamp(1, self.points.len() - 1)
+    }
+
+    // https://en.wikipedia.org/wiki/Monotone_cubic_interpolation
+    fn monotone_tangents(points: &[(f32, f32)]) -> Vec<f32> {
+        let secants: Vec<f32> = points
+            .windows(2)
+            .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+            .collect();
+
+        let mut tangents = Vec::with_capacity(points.len());
+
+        tangents.push(secants[0]);
+
+        for pair in secants.windows(2) {
+            tangents.push(if pair[0] * pair[1] <= 0.0 {
+                0.0
+            } else {
+                (pair[0] + pair[1]) / 2.0
+            });
+        }
+
+        tangents.push(secants[secants.len() - 1]);
+
+        for (index, secant) in secants.into_iter().enumerate() {
+            if secant == 0.0 {
+                tangents[index] = 0.0;
+                tangents[index + 1] = 0.0;
+
+                continue;
+            }
+
+            let alpha = tangents[index] / secant;
+            let beta = tangents[index + 1] / secant;
+            let length = alpha.hypot(beta);
+
+            if length > 3.0 {
+                let tau = 3.0 / length;
+
+                tangents[index] = tau * alpha * secant;
+                tangents[index + 1] = tau * beta * secant;
+            }
+        }
+
+        tangents
+    }
+}
+
+impl ParametricCurve<f32> for Sampled {
+    fn transform_internal(&self, t: f32) -> f32 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+
+        if t <= first.0 {
+            return first.1;
+        }
+
+        if t >= last.0 {
+            return last.1;
+        }
+
+        let index = self.segment_end(t);
+        let ((x0, y0), (x1, y1)) = (self.points[index - 1], self.points[index]);
+        let width = x1 - x0;
+        let s = (t - x0) / width;
+
+        match self.interpolation {
+            Interpolation::Linear => (y1 - y0).mul_add(s, y0),
+            Interpolation::MonotoneCubic => hermite(
+                s,
+                y0,
+                width * self.tangents[index - 1],
+                y1,
+                width * self.tangents[index],
+            ),
+        }
+    }
+
+    fn preserves_endpoints(&self) -> bool {
+        self.points[0] == (0.0, 0.0) && self.points[self.points.len() - 1] == (1.0, 1.0)
+    }
+
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+
+        if t < first.0 || t > last.0 {
+            return Some(0.0);
+        }
+
+        // Points sharing the same `t` jump without a slope, so the segment
+        // before them is used
+        let Some(index) = (1..=self.segment_end(t))
+            .rev()
+            .find(|&index| self.points[index - 1].0 < self.points[index].0)
+        else {
+            return Some(0.0);
+        };
+        let ((x0, y0), (x1, y1)) = (self.points[index - 1], self.points[index]);
+        let width = x1 - x0;
+
+        Some(match self.interpolation {
+            Interpolation::Linear => (y1 - y0) / width,
+            Interpolation::MonotoneCubic => {
+                let s = (t - x0) / width;
+                let (m0, m1) = (self.tangents[index - 1], self.tangents[index]);
+
+                let d00 = 6.0 * s * (s - 1.0);
+                let d10 = (3.0 * s).mul_add(s, -4.0 * s) + 1.0;
+                let d11 = (3.0 * s).mul_add(s, -2.0 * s);
+
+                (d00 * (y0 - y1)) / width + d10 * m0 + d11 * m1
+            }
+        })
+    }
+}
+
+/// Evaluates the cubic Hermite segment going from `p0` to `p1` with tangents
+/// `m0` and `m1` at `s` in `0.0..=1.0`.
+pub(super) fn hermite(s: f32, p0: f32, m0: f32, p1: f32, m1: f32) -> f32 {
+    let (s2, s3) = (s * s, s * s * s);
+
+    let h00 = 2.0f32.mul_add(s3, -3.0 * s2) + 1.0;
+    let h10 = s3 - 2.0 * s2 + s;
+    let h01 = (-2.0f32).mul_add(s3, 3.0 * s2);
+    let h11 = s3 - s2;
+
+    h00 * p0 + h10 * m0 + h01 * p1 + h11 * m1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interpolation, Sampled};
+    use crate::curves::ParametricCurve;
+
+    #[test]
+    fn derivative_at_repeated_points() {
+        let curve = Sampled::new(
+            [(0.0, 0.0), (0.5, 0.5), (0.5, 0.75), (1.0, 1.0), (1.0, 1.0)],
+            Interpolation::Linear,
+        );
+
+        assert_eq!(curve.derivative_internal(0.25), Some(1.0));
+        assert_eq!(curve.derivative_internal(1.0), Some(0.5));
+
+        let flat = Sampled::new([(0.5, 0.0), (0.5, 1.0)], Interpolation::Linear);
+
+        assert_eq!(flat.derivative_internal(0.5), Some(0.0));
+    }
+}
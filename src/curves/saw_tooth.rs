@@ -5,10 +5,21 @@ pub struct SawTooth {
     pub(super) count: f32,
 }
 
+impl SawTooth {
+    #[must_use]
+    pub const fn count(&self) -> f32 {
+        self.count
+    }
+}
+
 impl ParametricCurve<f32> for SawTooth {
     fn transform_internal(&self, mut t: f32) -> f32 {
         t *= self.count;
 
         t - t.trunc()
     }
+
+    fn derivative_internal(&self, _: f32) -> Option<f32> {
+        Some(self.count)
+    }
 }
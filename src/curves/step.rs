@@ -1,43 +1,181 @@
 use super::ParametricCurve;
 
+/// Where the jumps of a [`Stepped`] curve happen, matching the CSS `steps()`
+/// `<step-position>` keywords.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins (`jump-start`, `start`).
+    JumpStart,
+    /// The last jump happens when the animation ends (`jump-end`, `end`).
+    #[default]
+    JumpEnd,
+    /// There is no jump at either end, the first and last steps are held for
+    /// the same amount of time as the others (`jump-none`).
+    JumpNone,
+    /// There is a jump both at the beginning and at the end (`jump-both`).
+    JumpBoth,
+}
+
+impl StepPosition {
+    /// The CSS keyword of this step position.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::JumpStart => "jump-start",
+            Self::JumpEnd => "jump-end",
+            Self::JumpNone => "jump-none",
+            Self::JumpBoth => "jump-both",
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct Stepped {
-    pub(super) is_initial_step_single_frame: bool,
-    pub(super) is_final_step_single_frame: bool,
     pub(super) step_count: usize,
+    pub(super) position: StepPosition,
 }
 
 impl Stepped {
     #[must_use]
-    pub const fn new(step_count: usize) -> Self {
+    pub const fn new(step_count: usize, position: StepPosition) -> Self {
+        assert!(step_count > 0, "step count must be positive");
+        assert!(
+            step_count > 1 || !matches!(position, StepPosition::JumpNone),
+            "jump-none requires at least two steps"
+        );
+
         Self {
-            is_initial_step_single_frame: false,
-            is_final_step_single_frame: false,
             step_count,
+            position,
         }
     }
 
-    pub const fn initial_step_single_frame(&mut self) {
-        self.is_initial_step_single_frame = true;
+    #[must_use]
+    pub const fn step_count(&self) -> usize {
+        self.step_count
     }
 
-    pub const fn final_step_single_frame(&mut self) {
-        self.is_final_step_single_frame = true;
+    #[must_use]
+    pub const fn position(&self) -> StepPosition {
+        self.position
+    }
+
+    const fn jumps(&self) -> usize {
+        match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => self.step_count,
+            StepPosition::JumpNone => self.step_count - 1,
+            StepPosition::JumpBoth => self.step_count + 1,
+        }
     }
 }
 
 impl ParametricCurve<f32> for Stepped {
+    // https://drafts.csswg.org/css-easing/#step-easing-algo
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
     fn transform_internal(&self, t: f32) -> f32 {
-        let mut step_time = t * self.step_count as f32;
-
-        if self.is_initial_step_single_frame && t > 0.0 {
-            step_time = step_time.ceil();
-        } else if self.is_final_step_single_frame && t < 1.0 {
-            step_time = step_time.floor();
-        } else {
-            step_time = step_time.round();
+        let t = f64::from(t);
+        let jumps = self.jumps() as f64;
+        let mut step = (t * self.step_count as f64).floor();
+
+        if matches!(
+            self.position,
+            StepPosition::JumpStart | StepPosition::JumpBoth
+        ) {
+            step += 1.0;
         }
 
-        step_time / self.step_count as f32
+        if t >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+
+        if t <= 1.0 && step > jumps {
+            step = jumps;
+        }
+
+        (step / jumps) as f32
+    }
+
+    fn preserves_endpoints(&self) -> bool {
+        false
+    }
+
+    fn derivative_internal(&self, _: f32) -> Option<f32> {
+        Some(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StepPosition;
+    use crate::curves::{Curve, ICurve};
+
+    fn assert_steps(curve: &Curve, expected: &[(f32, f32)]) {
+        for &(t, y) in expected {
+            assert_eq!(curve.transform(t), y, "at t = {t}");
+        }
+    }
+
+    #[test]
+    fn jump_start() {
+        assert_steps(
+            &Curve::steps(4, StepPosition::JumpStart),
+            &[
+                (0.0, 0.25),
+                (0.24, 0.25),
+                (0.25, 0.5),
+                (0.99, 1.0),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn jump_end() {
+        assert_steps(
+            &Curve::steps(4, StepPosition::JumpEnd),
+            &[
+                (0.0, 0.0),
+                (0.24, 0.0),
+                (0.25, 0.25),
+                (0.99, 0.75),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn jump_none() {
+        assert_steps(
+            &Curve::steps(5, StepPosition::JumpNone),
+            &[
+                (0.0, 0.0),
+                (0.19, 0.0),
+                (0.2, 0.25),
+                (0.99, 1.0),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn jump_both() {
+        assert_steps(
+            &Curve::steps(3, StepPosition::JumpBoth),
+            &[
+                (0.0, 0.25),
+                (0.33, 0.25),
+                (0.34, 0.5),
+                (0.99, 0.75),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_single_frame_flags() {
+        assert!(Curve::stepped(4, true, false) == Curve::steps(4, StepPosition::JumpStart));
+        assert!(Curve::stepped(4, false, true) == Curve::steps(4, StepPosition::JumpEnd));
+        assert!(Curve::stepped(4, false, false) == Curve::steps(5, StepPosition::JumpNone));
     }
 }
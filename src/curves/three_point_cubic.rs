@@ -1,4 +1,4 @@
-use super::{Cubic, ICurve, ParametricCurve};
+use super::{Cubic, ParametricCurve};
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct Offset {
@@ -18,6 +18,12 @@ impl From<(f32, f32)> for Offset {
     }
 }
 
+/// Two cubic Béziers joined at `midpoint`, with the first one going from
+/// `(0.0, 0.0)` to `midpoint` and the second one from `midpoint` to
+/// `(1.0, 1.0)`.
+///
+/// Both halves are rescaled into standalone [`Cubic`] curves once at
+/// construction.
 #[derive(Clone, Copy, PartialEq)]
 pub struct ThreePointCubic {
     a1: Offset,
@@ -25,6 +31,8 @@ pub struct ThreePointCubic {
     midpoint: Offset,
     a2: Offset,
     b2: Offset,
+    first: Cubic,
+    second: Cubic,
 }
 
 impl ThreePointCubic {
@@ -35,52 +43,86 @@ impl ThreePointCubic {
         a2: (f32, f32),
         b2: (f32, f32),
     ) -> Self {
+        let (a1, b1, midpoint, a2, b2) = (
+            Offset::from_tuple(a1),
+            Offset::from_tuple(b1),
+            Offset::from_tuple(midpoint),
+            Offset::from_tuple(a2),
+            Offset::from_tuple(b2),
+        );
+
+        let (first_scale_x, first_scale_y) = (midpoint.dx, midpoint.dy);
+        let (second_scale_x, second_scale_y) = (1.0 - midpoint.dx, 1.0 - midpoint.dy);
+
         Self {
-            a1: Offset::from_tuple(a1),
-            b1: Offset::from_tuple(b1),
-            midpoint: Offset::from_tuple(midpoint),
-            a2: Offset::from_tuple(a2),
-            b2: Offset::from_tuple(b2),
+            a1,
+            b1,
+            midpoint,
+            a2,
+            b2,
+            first: Cubic::new(
+                a1.dx / first_scale_x,
+                a1.dy / first_scale_y,
+                b1.dx / first_scale_x,
+                b1.dy / first_scale_y,
+            ),
+            second: Cubic::new(
+                (a2.dx - midpoint.dx) / second_scale_x,
+                (a2.dy - midpoint.dy) / second_scale_y,
+                (b2.dx - midpoint.dx) / second_scale_x,
+                (b2.dy - midpoint.dy) / second_scale_y,
+            ),
+        }
+    }
+
+    /// The points the curve was built from, in the same order as in
+    /// [`ThreePointCubic::new`].
+    #[must_use]
+    pub const fn points(&self) -> [(f32, f32); 5] {
+        [
+            (self.a1.dx, self.a1.dy),
+            (self.b1.dx, self.b1.dy),
+            (self.midpoint.dx, self.midpoint.dy),
+            (self.a2.dx, self.a2.dy),
+            (self.b2.dx, self.b2.dy),
+        ]
+    }
+
+    /// Evaluates the curve at `t` with the given solver precision, see
+    /// [`Cubic::solve`].
+    #[must_use]
+    pub fn solve(&self, t: f32, error_bound: f32) -> f32 {
+        if t < self.midpoint.dx {
+            let scaled_t = t / self.midpoint.dx;
+
+            self.first.solve(scaled_t, error_bound) * self.midpoint.dy
+        } else {
+            let scale_x = 1.0 - self.midpoint.dx;
+            let scale_y = 1.0 - self.midpoint.dy;
+            let scaled_t = (t - self.midpoint.dx) / scale_x;
+
+            self.second
+                .solve(scaled_t, error_bound)
+                .mul_add(scale_y, self.midpoint.dy)
         }
     }
 }
 
 impl ParametricCurve<f32> for ThreePointCubic {
     fn transform_internal(&self, t: f32) -> f32 {
-        let first_curve = t < self.midpoint.dx;
-        let scale_x = if first_curve {
-            self.midpoint.dx
-        } else {
-            1.0 - self.midpoint.dx
-        };
-
-        let scale_y = if first_curve {
-            self.midpoint.dy
-        } else {
-            1.0 - self.midpoint.dy
-        };
+        self.solve(t, Cubic::SOLVER_ERROR_BOUND)
+    }
 
-        let scaled_t = (t - (if first_curve { 0.0 } else { self.midpoint.dx })) / scale_x;
+    fn derivative_internal(&self, t: f32) -> Option<f32> {
+        if t < self.midpoint.dx {
+            let slope = self.first.slope(t / self.midpoint.dx)?;
 
-        if first_curve {
-            Cubic::new(
-                self.a1.dx / scale_x,
-                self.a1.dy / scale_y,
-                self.b1.dx / scale_x,
-                self.b1.dy / scale_y,
-            )
-            .transform(scaled_t)
-                * scale_y
+            Some(slope * self.midpoint.dy / self.midpoint.dx)
         } else {
-            Cubic::new(
-                (self.a2.dx - self.midpoint.dx) / scale_x,
-                (self.a2.dy - self.midpoint.dy) / scale_y,
-                (self.b2.dx - self.midpoint.dx) / scale_x,
-                (self.b2.dy - self.midpoint.dy) / scale_y,
-            )
-            .transform(scaled_t)
-                * scale_y
-                + self.midpoint.dy
+            let scale_x = 1.0 - self.midpoint.dx;
+            let slope = self.second.slope((t - self.midpoint.dx) / scale_x)?;
+
+            Some(slope * (1.0 - self.midpoint.dy) / scale_x)
         }
     }
 }
@@ -5,6 +5,13 @@ pub struct Threshold {
     pub(super) threshold: f32,
 }
 
+impl Threshold {
+    #[must_use]
+    pub const fn threshold(&self) -> f32 {
+        self.threshold
+    }
+}
+
 impl ParametricCurve<f32> for Threshold {
     fn transform_internal(&self, t: f32) -> f32 {
         assert!((0.0..=1.0).contains(&self.threshold));
@@ -15,4 +22,8 @@ impl ParametricCurve<f32> for Threshold {
             1.0
         }
     }
+
+    fn derivative_internal(&self, _: f32) -> Option<f32> {
+        Some(0.0)
+    }
 }
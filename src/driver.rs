@@ -0,0 +1,202 @@
+use freya::{
+    hooks::{use_platform, UsePlatform},
+    prelude::{spawn_forever, use_root_context, CopyValue, Readable, ScopeId, Writable},
+};
+use indexmap::{IndexMap, IndexSet};
+
+/// Identifies an animation registered with an [`AnimationDriver`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AnimationJob(u64);
+
+type Frame = Box<dyn FnMut() -> bool>;
+
+struct Registry {
+    /// The frame callbacks of the running jobs, taken out while they're
+    /// called.
+    jobs: IndexMap<AnimationJob, Option<Frame>>,
+    /// Jobs kept registered without being called, see
+    /// [`AnimationDriver::suspend`].
+    suspended: IndexSet<AnimationJob>,
+    next_id: u64,
+    is_running: bool,
+}
+
+/// Advances every animation of the app once per frame, in a single task.
+///
+/// Every hook of the crate registers its animations with it. It's created at
+/// the root of the app the first time one of them is used, and stops
+/// requesting frames as soon as no animation is running or every one of them
+/// is suspended.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AnimationDriver {
+    registry: CopyValue<Registry>,
+    platform: UsePlatform,
+}
+
+impl AnimationDriver {
+    /// Calls `frame` on every frame until it returns `false` or the job is
+    /// cancelled.
+    pub fn register(&self, frame: impl FnMut() -> bool + 'static) -> AnimationJob {
+        let mut registry = self.registry;
+        let job = {
+            let mut registry = registry.write();
+            let job = AnimationJob(registry.next_id);
+
+            registry.next_id += 1;
+            registry.jobs.insert(job, Some(Box::new(frame)));
+
+            job
+        };
+
+        self.ensure_running();
+
+        job
+    }
+
+    /// Stops calling the frame callback of `job` without cancelling it, until
+    /// [`AnimationDriver::wake`] is called. Used for paused animations, which
+    /// don't need frames.
+    pub fn suspend(&self, job: AnimationJob) {
+        let mut registry = self.registry;
+
+        if registry.peek().jobs.contains_key(&job) {
+            registry.write().suspended.insert(job);
+        }
+    }
+
+    /// Calls the frame callback of a suspended `job` again.
+    pub fn wake(&self, job: AnimationJob) {
+        let mut registry = self.registry;
+
+        if registry.write().suspended.shift_remove(&job) {
+            self.ensure_running();
+        }
+    }
+
+    /// Stops calling the frame callback of `job`.
+    pub fn cancel(&self, job: AnimationJob) {
+        let mut registry = self.registry;
+        let frame = {
+            let mut registry = registry.write();
+
+            registry.suspended.shift_remove(&job);
+            registry.jobs.shift_remove(&job)
+        };
+
+        // Frames can hold values running code when dropped
+        drop(frame);
+    }
+
+    /// Whether `job` is still running, or suspended.
+    #[must_use]
+    pub fn is_active(&self, job: AnimationJob) -> bool {
+        self.registry.peek().jobs.contains_key(&job)
+    }
+
+    /// The number of running animations, the suspended ones aside.
+    #[must_use]
+    pub fn active_count(&self) -> usize {
+        let registry = self.registry.peek();
+
+        registry.jobs.len() - registry.suspended.len()
+    }
+
+    /// Whether frames are being requested.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.registry.peek().is_running
+    }
+
+    fn ensure_running(&self) {
+        let mut registry = self.registry;
+
+        if !registry.peek().is_running {
+            registry.write().is_running = true;
+
+            self.start();
+        }
+    }
+
+    fn start(&self) {
+        let mut registry = self.registry;
+        let platform = self.platform;
+
+        spawn_forever(async move {
+            let mut ticker = platform.new_ticker();
+
+            loop {
+                platform.request_animation_frame();
+
+                // Wait for the event loop to tick
+                ticker.tick().await;
+
+                // Jobs registered by the frames run from the next one
+                let jobs = {
+                    let registry = registry.peek();
+
+                    registry
+                        .jobs
+                        .keys()
+                        .filter(|job| !registry.suspended.contains(*job))
+                        .copied()
+                        .collect::<Vec<_>>()
+                };
+
+                for job in jobs {
+                    let Some(mut frame) =
+                        registry.write().jobs.get_mut(&job).and_then(Option::take)
+                    else {
+                        continue;
+                    };
+
+                    // Frames can register and cancel jobs, so they run outside
+                    // of the registry, which still knows their jobs.
+                    let is_running = frame();
+                    let finished = {
+                        let mut registry = registry.write();
+
+                        match registry.jobs.get_mut(&job) {
+                            Some(slot) if is_running => slot.replace(frame),
+                            Some(_) => {
+                                registry.jobs.shift_remove(&job);
+                                registry.suspended.shift_remove(&job);
+
+                                Some(frame)
+                            }
+                            // Cancelled by its own frame
+                            None => Some(frame),
+                        }
+                    };
+
+                    drop(finished);
+                }
+
+                let mut registry = registry.write();
+
+                if registry.jobs.len() == registry.suspended.len() {
+                    registry.is_running = false;
+
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// The [`AnimationDriver`] of the app, created on first use.
+pub fn use_animation_driver() -> AnimationDriver {
+    let platform = use_platform();
+
+    use_root_context(|| AnimationDriver {
+        registry: CopyValue::new_in_scope(
+            Registry {
+                jobs: IndexMap::new(),
+                suspended: IndexSet::new(),
+                next_id: 0,
+                is_running: false,
+            },
+            ScopeId::ROOT,
+        ),
+        platform,
+    })
+}
@@ -0,0 +1,267 @@
+//! Loads animation definitions from RON, JSON or TOML files, enabled by the
+//! `file` feature.
+//!
+//! In debug builds the files are watched and the animations rebuilt whenever
+//! they change, so that timings can be tweaked without restarting the app.
+
+use crate::{
+    segmented_animation::{Context, SegmentedAnimation},
+    use_segmented_animation, Curve, MotionCurve, MotionDuration, MotionTheme, Value,
+};
+use freya::prelude::{use_hook, use_signal, ReadOnlySignal, Readable};
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Why an animation file couldn't be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file extension isn't one of `ron`, `json` or `toml`.
+    UnknownFormat(PathBuf),
+    /// The file isn't a valid definition.
+    Parse(String),
+    /// The file couldn't be watched for changes.
+    Watch(notify::Error),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => error.fmt(f),
+            Self::UnknownFormat(path) => write!(
+                f,
+                "unknown format for {}, expected a .ron, .json or .toml file",
+                path.display()
+            ),
+            Self::Parse(error) => f.write_str(error),
+            Self::Watch(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Watch(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Reads a definition from `path`, picking the format from its extension.
+///
+/// # Errors
+///
+/// See [`LoadError`].
+pub fn load<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T, LoadError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)?;
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ron") => ron::from_str(&content).map_err(|error| LoadError::Parse(error.to_string())),
+        Some("json") => {
+            serde_json::from_str(&content).map_err(|error| LoadError::Parse(error.to_string()))
+        }
+        Some("toml") => {
+            toml::from_str(&content).map_err(|error| LoadError::Parse(error.to_string()))
+        }
+        _ => Err(LoadError::UnknownFormat(path.to_path_buf())),
+    }
+}
+
+/// The serializable counterpart of the closure given to
+/// [`use_segmented_animation`].
+///
+/// Curves and durations can be given as [`MotionTheme`] tokens, which must
+/// be defined by the current theme when the definition is loaded.
+///
+/// ```ron
+/// (
+///     tweens: { "opacity": 0.0, "offset": 0.0 },
+///     segments: [
+///         (tween: "opacity", value: 1.0, curve: "fast-out-slow-in", duration: 1000),
+///         (tween: "offset", value: 256.0, curve: "emphasized", duration: "medium2"),
+///     ],
+/// )
+/// ```
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentedAnimationDefinition {
+    /// Initial value of every tween.
+    pub tweens: IndexMap<String, Value>,
+    /// Segments, played one after the other.
+    #[serde(default)]
+    pub segments: Vec<SegmentDefinition>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentDefinition {
+    pub tween: String,
+    pub value: Value,
+    #[serde(
+        default = "default_curve",
+        deserialize_with = "crate::serialization::known_curve"
+    )]
+    pub curve: MotionCurve,
+    #[serde(deserialize_with = "crate::serialization::known_duration")]
+    pub duration: MotionDuration,
+}
+
+const fn default_curve() -> MotionCurve {
+    MotionCurve::Curve(Curve::None)
+}
+
+impl SegmentedAnimationDefinition {
+    /// Adds the tweens and segments to `context`.
+    ///
+    /// Tokens the current theme doesn't define, when it was swapped after the
+    /// definition was loaded, fall back to a linear curve and no duration.
+    pub fn build(&self, context: &mut Context) {
+        for (key, value) in &self.tweens {
+            context.add_tween(key.clone(), value.clone());
+        }
+
+        for segment in &self.segments {
+            let (curve, duration) = MotionTheme::with_current(|theme| {
+                (
+                    theme
+                        .try_resolve_curve(&segment.curve)
+                        .unwrap_or(Curve::LINEAR),
+                    theme
+                        .try_resolve_duration(&segment.duration)
+                        .unwrap_or_default(),
+                )
+            });
+
+            context.add_segment(
+                segment.tween.clone(),
+                segment.value.clone(),
+                curve,
+                duration,
+            );
+        }
+    }
+}
+
+/// Loads a definition from `path`, for example a
+/// [`KeyFrameAnimation`](crate::KeyFrameAnimation) or a
+/// [`PathAnimation`](crate::PathAnimation).
+///
+/// In debug builds the file is watched and the signal updated whenever it
+/// changes. Invalid changes are ignored, the second signal holding why the
+/// last reload failed until the file is fixed.
+///
+/// # Panics
+///
+/// Panics if the file can't be loaded on the first render.
+pub fn use_animation_definition<T: DeserializeOwned + 'static>(
+    path: impl Into<PathBuf>,
+) -> (ReadOnlySignal<T>, ReadOnlySignal<Option<LoadError>>) {
+    let path = use_hook(|| path.into());
+    let definition = use_signal(|| {
+        load(&path).unwrap_or_else(|error| panic!("failed to load {}: {error}", path.display()))
+    });
+    let error = use_signal(|| None);
+
+    #[cfg(debug_assertions)]
+    use_hook(|| {
+        freya::prelude::spawn(watch(path, definition, error));
+    });
+
+    (definition.into(), error.into())
+}
+
+/// Same as [`use_segmented_animation`], with the tweens and segments read from
+/// a [`SegmentedAnimationDefinition`] stored at `path`. In debug builds the
+/// animation is rebuilt whenever the file changes, see
+/// [`use_animation_definition`] for the errors.
+///
+/// # Panics
+///
+/// Panics if the file can't be loaded on the first render.
+pub fn use_animation_file(
+    path: impl Into<PathBuf>,
+) -> (SegmentedAnimation, ReadOnlySignal<Option<LoadError>>) {
+    let (definition, error) = use_animation_definition::<SegmentedAnimationDefinition>(path);
+
+    (
+        use_segmented_animation(move |context| definition.read().build(context)),
+        error,
+    )
+}
+
+#[cfg(debug_assertions)]
+async fn watch<T: DeserializeOwned + 'static>(
+    path: PathBuf,
+    mut definition: freya::prelude::Signal<T>,
+    mut last_error: freya::prelude::Signal<Option<LoadError>>,
+) {
+    use freya::prelude::Writable;
+    use notify::{Event, RecursiveMode, Watcher};
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let file_name = path.file_name().map(ToOwned::to_owned);
+
+    let watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            if (event.kind.is_create() || event.kind.is_modify())
+                && event
+                    .paths
+                    .iter()
+                    .any(|changed| changed.file_name() == file_name.as_deref())
+            {
+                let _ = sender.send(());
+            }
+        }
+    });
+
+    // Editors often save by replacing the file, so the parent directory is
+    // watched rather than the file itself.
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let watched = watcher.and_then(|mut watcher| {
+        watcher
+            .watch(directory, RecursiveMode::NonRecursive)
+            .map(|()| watcher)
+    });
+
+    // Dropping the watcher stops it
+    let _watcher = match watched {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            last_error.set(Some(LoadError::Watch(error)));
+
+            return;
+        }
+    };
+
+    while receiver.recv().await.is_some() {
+        // A single save usually produces several events.
+        while receiver.try_recv().is_ok() {}
+
+        match load(&path) {
+            Ok(value) => {
+                definition.set(value);
+
+                if last_error.peek().is_some() {
+                    last_error.set(None);
+                }
+            }
+            Err(error) => last_error.set(Some(error)),
+        }
+    }
+}
@@ -1,14 +1,39 @@
 mod animations;
+mod callbacks;
+mod clock;
+mod completion;
+mod components;
 pub mod curves;
+mod driver;
+#[cfg(feature = "file")]
+pub mod file;
+mod playhead;
+mod preference;
+pub mod preview;
+mod repeat;
 mod segmented_animation;
+#[cfg(feature = "serde")]
+mod serialization;
+mod signals;
+mod theme;
 mod trait_based;
 mod transition;
 mod tween;
 
+#[cfg(feature = "file")]
+pub use self::file::{use_animation_definition, use_animation_file};
 pub use self::{
     animations::{Animation, KeyFrameAnimation, PathAnimation},
+    callbacks::AnimationEvent,
+    clock::{use_animation_clock, use_manual_animation_clock, AnimationClock, ManualClock},
+    completion::{AnimationOutcome, Completion},
+    components::CurveEditor,
     curves::Curve,
-    segmented_animation::use_segmented_animation,
+    driver::{use_animation_driver, AnimationDriver, AnimationJob},
+    preference::{use_motion_preference, MotionPreference},
+    repeat::{AnimationDirection, IterationCount, Repeat},
+    segmented_animation::{use_segmented_animation, Seek},
+    theme::{use_motion_theme, MotionCurve, MotionDuration, MotionTheme},
     trait_based::use_trait_animation,
     transition::use_transition,
     tween::{Gradient, Lerp, Tween, Value},
@@ -0,0 +1,114 @@
+use crate::MotionPreference;
+use std::time::Duration;
+
+/// Where a played animation is: it moves away from `offset` at `rate`,
+/// backwards if negative, since `start` on the
+/// [`AnimationClock`](crate::AnimationClock).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct Playhead {
+    start: Duration,
+    offset: Duration,
+    rate: f32,
+    is_paused: bool,
+}
+
+impl Playhead {
+    /// Plays an animation from its start, at its normal pace.
+    pub(crate) const fn new(start: Duration) -> Self {
+        Self {
+            start,
+            offset: Duration::ZERO,
+            rate: 1.0,
+            is_paused: false,
+        }
+    }
+
+    pub(crate) fn is_reversed(&self) -> bool {
+        self.rate < 0.0
+    }
+
+    pub(crate) const fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Whether the time changes from one frame to the next, so that the
+    /// animation needs frames.
+    pub(crate) fn is_moving(&self) -> bool {
+        !self.is_paused && self.rate != 0.0
+    }
+
+    /// Stops moving, at `time`.
+    pub(crate) fn pause(&mut self, time: Duration) {
+        self.offset = time;
+        self.is_paused = true;
+    }
+
+    /// Moves again from where it was paused.
+    pub(crate) fn resume(&mut self, now: Duration) {
+        self.start = now;
+        self.is_paused = false;
+    }
+
+    /// Jumps to `time`, moving from there unless paused.
+    pub(crate) fn seek(&mut self, now: Duration, time: Duration) {
+        self.start = now;
+        self.offset = time;
+    }
+
+    /// Moves backwards from `time`, at the same pace.
+    pub(crate) fn reverse(&mut self, now: Duration, time: Duration) {
+        let rate = if self.rate == 0.0 { 1.0 } else { self.rate };
+
+        self.set_rate(now, time, -rate.abs());
+        self.is_paused = false;
+    }
+
+    /// Moves from `time` at `rate`, so that the animation doesn't jump.
+    pub(crate) fn set_rate(&mut self, now: Duration, time: Duration, rate: f32) {
+        self.start = now;
+        self.offset = time;
+        self.rate = rate;
+    }
+
+    /// The time of an animation lasting `duration`, `None` if it repeats
+    /// forever, played with `preference`.
+    pub(crate) fn time(
+        &self,
+        now: Duration,
+        duration: Option<Duration>,
+        preference: MotionPreference,
+    ) -> Duration {
+        if self.is_paused {
+            return duration.map_or(self.offset, |duration| self.offset.min(duration));
+        }
+
+        let elapsed = now.saturating_sub(self.start).mul_f32(self.rate.abs());
+
+        match duration {
+            Some(duration) => {
+                let played = preference.time(elapsed, duration);
+
+                if self.is_reversed() {
+                    self.offset.saturating_sub(played)
+                } else {
+                    self.offset.saturating_add(played).min(duration)
+                }
+            }
+            // Animations repeating forever can't be shortened
+            None if self.is_reversed() => self.offset.saturating_sub(elapsed),
+            None => self.offset.saturating_add(elapsed),
+        }
+    }
+
+    /// Whether an animation lasting `duration` reached the end it's heading
+    /// to at `time`. Paused and stopped animations don't end.
+    pub(crate) fn is_done(&self, time: Duration, duration: Option<Duration>) -> bool {
+        if self.is_paused || self.rate == 0.0 {
+            false
+        } else if self.is_reversed() {
+            time.is_zero()
+        } else {
+            duration.is_some_and(|duration| time >= duration)
+        }
+    }
+}
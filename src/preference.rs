@@ -0,0 +1,93 @@
+use crate::{Lerp, Value};
+use freya::prelude::{try_consume_context, use_context_provider, Readable, Signal};
+use std::time::Duration;
+
+/// How much motion the user wants, provided to the app with
+/// [`use_motion_preference`].
+///
+/// freya doesn't expose the reduced motion setting of the operating system,
+/// so it's up to the app to read it and set the preference.
+///
+/// Tweens marked as essential with `Context::set_essential` are always played
+/// in full.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum MotionPreference {
+    /// Animations are played as they are defined.
+    #[default]
+    Full,
+    /// Animations are replaced by linear cross-fades from their first to
+    /// their last values, lasting at most
+    /// [`MotionPreference::REDUCED_DURATION`]. Animations repeating forever
+    /// hold their first values.
+    Reduced,
+    /// Animations jump to their final values.
+    None,
+}
+
+impl MotionPreference {
+    /// The longest an animation can last with [`MotionPreference::Reduced`].
+    pub const REDUCED_DURATION: Duration = Duration::from_millis(150);
+
+    /// How long an animation lasting `duration` is played for.
+    #[must_use]
+    pub fn duration(self, duration: Duration) -> Duration {
+        match self {
+            Self::Full => duration,
+            Self::Reduced => duration.min(Self::REDUCED_DURATION),
+            Self::None => Duration::ZERO,
+        }
+    }
+
+    /// Maps the time elapsed since an animation lasting `duration` started to
+    /// the point of the animation that should be shown.
+    #[must_use]
+    pub fn time(self, elapsed: Duration, duration: Duration) -> Duration {
+        let played = self.duration(duration);
+
+        if played == duration {
+            elapsed
+        } else if played.is_zero() {
+            duration
+        } else {
+            elapsed.mul_f64(duration.as_secs_f64() / played.as_secs_f64())
+        }
+    }
+
+    /// The value at `time` of an animation lasting `duration`, `None` if it
+    /// repeats forever, evaluated by `value_at`.
+    pub(crate) fn value_at(
+        self,
+        time: Duration,
+        duration: Option<Duration>,
+        mut value_at: impl FnMut(Duration) -> Value,
+    ) -> Value {
+        match (self, duration) {
+            (Self::Reduced, Some(duration)) if !duration.is_zero() => value_at(Duration::ZERO)
+                .lerp(
+                    &value_at(duration),
+                    time.div_duration_f32(duration).min(1.0),
+                ),
+            (Self::Reduced, None) => value_at(Duration::ZERO),
+            _ => value_at(time),
+        }
+    }
+
+    /// The preference provided by [`use_motion_preference`] in an ancestor
+    /// component, or [`MotionPreference::Full`] if there's none.
+    #[must_use]
+    pub fn current() -> Self {
+        try_consume_context::<Signal<Self>>()
+            .map(|preference| *preference.peek())
+            .unwrap_or_default()
+    }
+}
+
+/// Provides a [`MotionPreference`] to the descendants of the current
+/// component, for example `use_motion_preference(|| MotionPreference::Reduced)`.
+/// Setting the returned signal changes the preference of the animations
+/// played afterwards.
+pub fn use_motion_preference(
+    preference: impl FnOnce() -> MotionPreference,
+) -> Signal<MotionPreference> {
+    use_context_provider(|| Signal::new(preference()))
+}
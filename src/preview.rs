@@ -0,0 +1,557 @@
+//! Renders previews of curves, either rasterized to PNG on the CPU or as SVG.
+
+use crate::{curves::ICurve, Curve};
+use skia_safe::{
+    surfaces, Canvas, Color, EncodedImageFormat, Font, FontMgr, FontStyle, Paint, PaintStyle, Path,
+    PathEffect, Rect,
+};
+use std::fmt::Write;
+
+pub(crate) const PADDING: f32 = 16.0;
+const LABEL_HEIGHT: f32 = 20.0;
+
+const BACKGROUND: Color = Color::WHITE;
+const OVERSHOOT: Color = Color::from_argb(0x20, 0xE5, 0x39, 0x35);
+const GRID: Color = Color::from_argb(0xFF, 0xEE, 0xEE, 0xEE);
+const AXES: Color = Color::from_argb(0xFF, 0xBD, 0xBD, 0xBD);
+const VELOCITY: Color = Color::from_argb(0xFF, 0xFB, 0x8C, 0x00);
+const CURVE: Color = Color::from_argb(0xFF, 0x1E, 0x88, 0xE5);
+const LABEL: Color = Color::from_argb(0xFF, 0x42, 0x42, 0x42);
+
+/// Pixel positions of everything drawn in a preview.
+struct Layout {
+    width: f32,
+    height: f32,
+    min: f32,
+    max: f32,
+    overshoot: (f32, f32),
+    curve: Vec<(f32, f32)>,
+    velocity: Option<Vec<(f32, f32)>>,
+}
+
+impl Layout {
+    fn x(&self, t: f32) -> f32 {
+        t.mul_add(self.width - PADDING * 2.0, PADDING)
+    }
+
+    fn y(&self, value: f32) -> f32 {
+        ((self.max - value) / (self.max - self.min)).mul_add(self.height - PADDING * 2.0, PADDING)
+    }
+}
+
+/// A preview of a curve, plotting `t` on the x axis and the transformed
+/// value on the y axis.
+///
+/// The `0.0..=1.0` square is always visible, and whatever the curve reaches
+/// outside of it is highlighted as overshoot.
+pub struct CurvePreview<'a> {
+    curve: &'a Curve,
+    width: u32,
+    height: u32,
+    samples: usize,
+    range: (f32, f32),
+    show_grid: bool,
+    show_velocity: bool,
+}
+
+impl<'a> CurvePreview<'a> {
+    #[must_use]
+    pub const fn new(curve: &'a Curve) -> Self {
+        Self {
+            curve,
+            width: 256,
+            height: 256,
+            samples: 256,
+            range: (0.0, 1.0),
+            show_grid: true,
+            show_velocity: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+
+        self
+    }
+
+    /// The range of values always visible on the y axis, which is extended to
+    /// fit the curve if needed. Defaults to `0.0..=1.0`.
+    #[must_use]
+    pub const fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = (min, max);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = show_grid;
+
+        self
+    }
+
+    /// Also plots the velocity of the curve, normalized so that its largest
+    /// magnitude reaches the top of the unit square.
+    #[must_use]
+    pub const fn velocity(mut self, show_velocity: bool) -> Self {
+        self.show_velocity = show_velocity;
+
+        self
+    }
+
+    /// The range of values actually shown on the y axis, once extended to fit
+    /// the curve.
+    pub(crate) fn visible_range(&self) -> (f32, f32) {
+        let layout = self.layout();
+
+        (layout.min, layout.max)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self) -> Layout {
+        let samples: Vec<(f32, f32)> = (0..=self.samples)
+            .map(|sample| {
+                let t = sample as f32 / self.samples as f32;
+
+                (t, self.curve.transform(t))
+            })
+            .collect();
+
+        let (lowest, highest) = samples.iter().fold((0.0f32, 1.0f32), |(min, max), (_, y)| {
+            (min.min(*y), max.max(*y))
+        });
+
+        let mut layout = Layout {
+            width: self.width as f32,
+            height: self.height as f32,
+            min: lowest.min(self.range.0),
+            max: highest.max(self.range.1),
+            overshoot: (lowest, highest),
+            curve: Vec::new(),
+            velocity: None,
+        };
+
+        if self.show_velocity {
+            let velocity: Vec<(f32, f32)> = samples
+                .iter()
+                .map(|(t, _)| (*t, self.curve.velocity(*t)))
+                .collect();
+            let peak = velocity
+                .iter()
+                .fold(0.0f32, |peak, (_, velocity)| peak.max(velocity.abs()));
+
+            layout.velocity = Some(
+                velocity
+                    .into_iter()
+                    .map(|(t, velocity)| {
+                        let velocity = if peak > 0.0 { velocity / peak } else { 0.0 };
+
+                        (
+                            layout.x(t),
+                            layout.y(velocity.clamp(layout.min, layout.max)),
+                        )
+                    })
+                    .collect(),
+            );
+        }
+
+        layout.curve = samples
+            .into_iter()
+            .map(|(t, y)| (layout.x(t), layout.y(y)))
+            .collect();
+
+        layout
+    }
+
+    /// Draws the preview onto `canvas`, with its top left corner at the origin.
+    pub fn draw(&self, canvas: &Canvas) {
+        let layout = self.layout();
+        let (left, right) = (layout.x(0.0), layout.x(1.0));
+
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true);
+        paint.set_color(BACKGROUND);
+
+        canvas.draw_rect(Rect::from_wh(layout.width, layout.height), &paint);
+
+        paint.set_color(OVERSHOOT);
+
+        let (lowest, highest) = layout.overshoot;
+
+        if highest > 1.0 {
+            canvas.draw_rect(
+                Rect::from_ltrb(left, layout.y(highest), right, layout.y(1.0)),
+                &paint,
+            );
+        }
+
+        if lowest < 0.0 {
+            canvas.draw_rect(
+                Rect::from_ltrb(left, layout.y(0.0), right, layout.y(lowest)),
+                &paint,
+            );
+        }
+
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(1.0);
+
+        if self.show_grid {
+            paint.set_color(GRID);
+
+            for line in 1..10u8 {
+                let value = f32::from(line) / 10.0;
+
+                canvas.draw_line(
+                    (layout.x(value), layout.y(0.0)),
+                    (layout.x(value), layout.y(1.0)),
+                    &paint,
+                );
+                canvas.draw_line((left, layout.y(value)), (right, layout.y(value)), &paint);
+            }
+        }
+
+        paint.set_color(AXES);
+
+        canvas.draw_rect(
+            Rect::from_ltrb(left, layout.y(1.0), right, layout.y(0.0)),
+            &paint,
+        );
+
+        paint.set_stroke_width(2.0);
+
+        if let Some(velocity) = &layout.velocity {
+            paint.set_color(VELOCITY);
+            paint.set_path_effect(PathEffect::dash(&[4.0, 4.0], 0.0));
+
+            canvas.draw_path(&Self::path(velocity), &paint);
+
+            paint.set_path_effect(None);
+        }
+
+        paint.set_color(CURVE);
+
+        canvas.draw_path(&Self::path(&layout.curve), &paint);
+    }
+
+    /// Rasterizes the preview on the CPU and encodes it as PNG.
+    #[must_use]
+    pub fn to_png(&self) -> Option<Vec<u8>> {
+        let mut surface = surfaces::raster_n32_premul((
+            i32::try_from(self.width).ok()?,
+            i32::try_from(self.height).ok()?,
+        ))?;
+
+        self.draw(surface.canvas());
+
+        encode_png(&mut surface)
+    }
+
+    /// Writes the preview as a standalone SVG document.
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            self.width, self.height
+        );
+
+        self.write_svg_content(&mut svg);
+
+        svg.push_str("</svg>");
+
+        svg
+    }
+
+    fn write_svg_content(&self, svg: &mut String) {
+        let layout = self.layout();
+        let (left, right) = (layout.x(0.0), layout.x(1.0));
+
+        let _ = write!(
+            svg,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            layout.width,
+            layout.height,
+            svg_color(BACKGROUND)
+        );
+
+        let (lowest, highest) = layout.overshoot;
+
+        for (from, to) in [(highest, 1.0), (0.0, lowest)] {
+            if from != to {
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{left}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    layout.y(from),
+                    right - left,
+                    layout.y(to) - layout.y(from),
+                    svg_color(OVERSHOOT)
+                );
+            }
+        }
+
+        if self.show_grid {
+            let _ = write!(svg, r#"<g stroke="{}" stroke-width="1">"#, svg_color(GRID));
+
+            for line in 1..10u8 {
+                let value = f32::from(line) / 10.0;
+
+                let _ = write!(
+                    svg,
+                    r#"<line x1="{0}" y1="{1}" x2="{0}" y2="{2}"/><line x1="{left}" y1="{3}" x2="{right}" y2="{3}"/>"#,
+                    layout.x(value),
+                    layout.y(0.0),
+                    layout.y(1.0),
+                    layout.y(value),
+                );
+            }
+
+            svg.push_str("</g>");
+        }
+
+        let _ = write!(
+            svg,
+            r#"<rect x="{left}" y="{}" width="{}" height="{}" fill="none" stroke="{}" stroke-width="1"/>"#,
+            layout.y(1.0),
+            right - left,
+            layout.y(0.0) - layout.y(1.0),
+            svg_color(AXES)
+        );
+
+        if let Some(velocity) = &layout.velocity {
+            let _ = write!(
+                svg,
+                r#"<path d="{}" fill="none" stroke="{}" stroke-width="2" stroke-dasharray="4 4"/>"#,
+                Self::svg_path(velocity),
+                svg_color(VELOCITY)
+            );
+        }
+
+        let _ = write!(
+            svg,
+            r#"<path d="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+            Self::svg_path(&layout.curve),
+            svg_color(CURVE)
+        );
+    }
+
+    fn path(points: &[(f32, f32)]) -> Path {
+        let mut path = Path::new();
+
+        for (index, point) in points.iter().enumerate() {
+            if index == 0 {
+                path.move_to(*point);
+            } else {
+                path.line_to(*point);
+            }
+        }
+
+        path
+    }
+
+    fn svg_path(points: &[(f32, f32)]) -> String {
+        let mut path = String::new();
+
+        for (index, (x, y)) in points.iter().enumerate() {
+            let _ = write!(path, "{}{x:.2} {y:.2}", if index == 0 { "M" } else { " L" });
+        }
+
+        path
+    }
+}
+
+fn encode_png(surface: &mut skia_safe::Surface) -> Option<Vec<u8>> {
+    surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .map(|data| data.as_bytes().to_vec())
+}
+
+fn svg_color(color: Color) -> String {
+    format!(
+        "rgba({}, {}, {}, {})",
+        color.r(),
+        color.g(),
+        color.b(),
+        f32::from(color.a()) / 255.0
+    )
+}
+
+/// Positions of the cells of a contact sheet, from left to right and top to
+/// bottom.
+fn contact_sheet_cells(
+    columns: usize,
+    cell: (u32, u32),
+) -> impl Iterator<Item = (&'static str, &'static Curve, f32, f32)> {
+    let columns = columns.max(1);
+
+    Curve::PRESETS
+        .iter()
+        .enumerate()
+        .map(move |(index, (name, curve))| {
+            #[allow(clippy::cast_precision_loss)]
+            let (x, y) = (
+                (index % columns) as f32 * cell.0 as f32,
+                (index / columns) as f32 * (cell.1 as f32 + LABEL_HEIGHT),
+            );
+
+            (*name, curve, x, y)
+        })
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn contact_sheet_size(columns: usize, cell: (u32, u32)) -> (f32, f32) {
+    let columns = columns.max(1);
+    let rows = Curve::PRESETS.len().div_ceil(columns);
+
+    (
+        columns as f32 * cell.0 as f32,
+        rows as f32 * (cell.1 as f32 + LABEL_HEIGHT),
+    )
+}
+
+/// Renders every [`Curve::PRESETS`] entry into a single PNG, `columns` previews
+/// wide.
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn contact_sheet(columns: usize, cell: (u32, u32)) -> Option<Vec<u8>> {
+    let (width, height) = contact_sheet_size(columns, cell);
+    let mut surface = surfaces::raster_n32_premul((width as i32, height as i32))?;
+    let canvas = surface.canvas();
+
+    let font = FontMgr::new()
+        .legacy_make_typeface(None, FontStyle::default())
+        .map(|typeface| Font::from_typeface(typeface, 12.0));
+
+    let mut paint = Paint::default();
+
+    paint.set_anti_alias(true);
+    paint.set_color(LABEL);
+
+    canvas.clear(BACKGROUND);
+
+    for (name, curve, x, y) in contact_sheet_cells(columns, cell) {
+        canvas.save();
+        canvas.translate((x, y));
+
+        if let Some(font) = &font {
+            canvas.draw_str(name, (PADDING, LABEL_HEIGHT - 4.0), font, &paint);
+        }
+
+        canvas.translate((0.0, LABEL_HEIGHT));
+
+        CurvePreview::new(curve).size(cell.0, cell.1).draw(canvas);
+
+        canvas.restore();
+    }
+
+    encode_png(&mut surface)
+}
+
+/// Like [`contact_sheet`], but as a standalone SVG document.
+#[must_use]
+pub fn contact_sheet_svg(columns: usize, cell: (u32, u32)) -> String {
+    let (width, height) = contact_sheet_size(columns, cell);
+    let mut svg = String::new();
+
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+
+    for (name, curve, x, y) in contact_sheet_cells(columns, cell) {
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{}" font-family="sans-serif" font-size="12" fill="{}">{name}</text><g transform="translate({x} {})">"#,
+            x + PADDING,
+            y + LABEL_HEIGHT - 4.0,
+            svg_color(LABEL),
+            y + LABEL_HEIGHT
+        );
+
+        CurvePreview::new(curve)
+            .size(cell.0, cell.1)
+            .write_svg_content(&mut svg);
+
+        svg.push_str("</g>");
+    }
+
+    svg.push_str("</svg>");
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contact_sheet_svg, CurvePreview};
+    use crate::Curve;
+
+    #[test]
+    fn linear_svg() {
+        assert_eq!(
+            CurvePreview::new(&Curve::LINEAR)
+                .size(48, 48)
+                .samples(2)
+                .grid(false)
+                .to_svg(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="48" height="48" viewBox="0 0 48 48">"#,
+                r#"<rect width="48" height="48" fill="rgba(255, 255, 255, 1)"/>"#,
+                r#"<rect x="16" y="16" width="16" height="16" fill="none" stroke="rgba(189, 189, 189, 1)" stroke-width="1"/>"#,
+                r#"<path d="M16.00 32.00 L24.00 24.00 L32.00 16.00" fill="none" stroke="rgba(30, 136, 229, 1)" stroke-width="2"/>"#,
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn overshoot_follows_the_curve_not_the_range() {
+        assert_eq!(
+            CurvePreview::new(&Curve::EASE_IN_OUT_BACK)
+                .size(48, 48)
+                .samples(4)
+                .grid(false)
+                .range(-1.0, 2.0)
+                .to_svg(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="48" height="48" viewBox="0 0 48 48">"#,
+                r#"<rect width="48" height="48" fill="rgba(255, 255, 255, 1)"/>"#,
+                r#"<rect x="16" y="20.857782" width="16" height="0.4755516" fill="rgba(229, 57, 53, 0.1254902)"/>"#,
+                r#"<rect x="16" y="26.666668" width="16" height="0.44163513" fill="rgba(229, 57, 53, 0.1254902)"/>"#,
+                r#"<rect x="16" y="21.333334" width="16" height="5.333334" fill="none" stroke="rgba(189, 189, 189, 1)" stroke-width="1"/>"#,
+                r#"<path d="M16.00 26.67 L20.00 27.11 L24.00 23.43 L28.00 20.86 L32.00 21.33" fill="none" stroke="rgba(30, 136, 229, 1)" stroke-width="2"/>"#,
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn contact_sheet_svg_cells() {
+        let svg = contact_sheet_svg(4, (48, 48));
+        let mut first_cell = String::from(concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="192" height="884" viewBox="0 0 192 884">"#,
+            r#"<text x="16" y="16" font-family="sans-serif" font-size="12" fill="rgba(66, 66, 66, 1)">none</text>"#,
+            r#"<g transform="translate(0 20)">"#,
+        ));
+
+        CurvePreview::new(&Curve::NONE)
+            .size(48, 48)
+            .write_svg_content(&mut first_cell);
+        first_cell.push_str("</g>");
+
+        assert!(svg.starts_with(&first_cell));
+        assert!(svg.ends_with("</g></svg>"));
+        assert_eq!(svg.matches("<text ").count(), Curve::PRESETS.len());
+    }
+}
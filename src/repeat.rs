@@ -0,0 +1,264 @@
+use std::time::Duration;
+
+/// How many times an animation plays, like the CSS
+/// `animation-iteration-count`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IterationCount {
+    /// A number of iterations, `1.5` stopping halfway through the second one.
+    Count(f32),
+    Infinite,
+}
+
+impl Default for IterationCount {
+    fn default() -> Self {
+        Self::Count(1.0)
+    }
+}
+
+impl From<f32> for IterationCount {
+    fn from(count: f32) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl From<u32> for IterationCount {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(count: u32) -> Self {
+        Self::Count(count as f32)
+    }
+}
+
+/// Which way the iterations of an animation play, like the CSS
+/// `animation-direction`. Reversed iterations also reverse their curves.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum AnimationDirection {
+    #[default]
+    Normal,
+    Reverse,
+    /// Even iterations play forwards, odd ones backwards.
+    Alternate,
+    /// Even iterations play backwards, odd ones forwards.
+    AlternateReverse,
+}
+
+impl AnimationDirection {
+    #[must_use]
+    pub const fn is_reversed(self, iteration: u32) -> bool {
+        match self {
+            Self::Normal => false,
+            Self::Reverse => true,
+            Self::Alternate => !iteration.is_multiple_of(2),
+            Self::AlternateReverse => iteration.is_multiple_of(2),
+        }
+    }
+}
+
+/// How an animation repeats. The default plays it once, forwards.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Repeat {
+    pub iterations: IterationCount,
+    pub direction: AnimationDirection,
+    /// Pause between two iterations.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::millis"))]
+    pub delay: Duration,
+    /// Iteration the animation starts at, `0.5` starting halfway through the
+    /// first one.
+    pub iteration_start: f32,
+}
+
+/// Where a repeating animation is.
+pub(crate) struct Position {
+    /// Index of the current iteration, which lasts until the next one starts.
+    pub(crate) iteration: u32,
+    /// Progress of the current iteration, from `0.0` to `1.0`, already
+    /// reversed if the iteration is.
+    pub(crate) progress: f64,
+}
+
+impl Repeat {
+    #[must_use]
+    pub fn is_once(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// How long all the iterations of an animation lasting `duration` take,
+    /// `None` if it repeats forever.
+    #[must_use]
+    pub fn total_duration(&self, duration: Duration) -> Option<Duration> {
+        let IterationCount::Count(count) = self.iterations else {
+            return None;
+        };
+        let start = self.start();
+        let end = start + f64::from(count.max(0.0));
+
+        Some(Duration::from_secs_f64(
+            (self.end_time(end, duration) - self.time(start, duration)).max(0.0),
+        ))
+    }
+
+    /// How far an animation lasting `duration` is `elapsed` after it started,
+    /// from `0.0` to `1.0`. For one repeating forever, it's how far the
+    /// current iteration is.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn progress(&self, elapsed: Duration, duration: Duration) -> f32 {
+        match self.total_duration(duration) {
+            Some(total) if total.is_zero() => 1.0,
+            Some(total) => elapsed.div_duration_f32(total).min(1.0),
+            None => {
+                let position = self.position(elapsed, duration);
+
+                // Whichever way the iteration plays
+                if self.direction.is_reversed(position.iteration) {
+                    (1.0 - position.progress) as f32
+                } else {
+                    position.progress as f32
+                }
+            }
+        }
+    }
+
+    /// Where an animation lasting `duration` is, `elapsed` after it started.
+    pub(crate) fn position(&self, elapsed: Duration, duration: Duration) -> Position {
+        let d = duration.as_secs_f64();
+        let length = d + self.delay.as_secs_f64();
+        let start = self.start();
+        let end = match self.iterations {
+            IterationCount::Count(count) => Some(start + f64::from(count.max(0.0))),
+            IterationCount::Infinite => None,
+        };
+        // Instant animations are over as soon as they start
+        let is_over = end.is_some_and(|end| {
+            if length == 0.0 {
+                !elapsed.is_zero()
+            } else {
+                elapsed.as_secs_f64() >= self.end_time(end, duration) - self.time(start, duration)
+            }
+        });
+
+        let (iteration, progress) = match end {
+            Some(end) if is_over => {
+                // Stop at the end of the last iteration, not the start of the next
+                let iteration = (end.ceil() - 1.0).max(0.0);
+
+                (iteration, (end - iteration).min(1.0))
+            }
+            _ if length == 0.0 => (start.floor(), start.fract()),
+            _ => {
+                let time = self.time(start, duration) + elapsed.as_secs_f64();
+                let iteration = (time / length).floor();
+                let played = time - iteration * length;
+
+                (
+                    iteration,
+                    if d == 0.0 { 1.0 } else { (played / d).min(1.0) },
+                )
+            }
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = iteration as u32;
+
+        Position {
+            iteration: index,
+            progress: if self.direction.is_reversed(index) {
+                1.0 - progress
+            } else {
+                progress
+            },
+        }
+    }
+
+    fn start(&self) -> f64 {
+        f64::from(self.iteration_start.max(0.0))
+    }
+
+    /// When the animation reaches `iterations`, counting from the beginning
+    /// of the first iteration.
+    fn time(&self, iterations: f64, duration: Duration) -> f64 {
+        iterations.floor() * (duration + self.delay).as_secs_f64()
+            + iterations.fract() * duration.as_secs_f64()
+    }
+
+    /// Same as [`Repeat::time`], but ending iterations don't wait for the
+    /// delay of the next one.
+    fn end_time(&self, iterations: f64, duration: Duration) -> f64 {
+        if iterations > 0.0 && iterations.fract() == 0.0 {
+            self.time(iterations - 1.0, duration) + duration.as_secs_f64()
+        } else {
+            self.time(iterations, duration)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimationDirection, IterationCount, Repeat};
+    use std::time::Duration;
+
+    const DURATION: Duration = Duration::from_secs(1);
+
+    fn position(repeat: &Repeat, millis: u64) -> (u32, f64) {
+        let position = repeat.position(Duration::from_millis(millis), DURATION);
+
+        (position.iteration, position.progress)
+    }
+
+    #[test]
+    fn fractional_count() {
+        let repeat = Repeat {
+            iterations: IterationCount::Count(1.5),
+            ..Repeat::default()
+        };
+
+        assert_eq!(
+            repeat.total_duration(DURATION),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(position(&repeat, 1250), (1, 0.25));
+        assert_eq!(position(&repeat, 1500), (1, 0.5));
+        assert_eq!(position(&repeat, 3000), (1, 0.5));
+    }
+
+    #[test]
+    fn alternate_reverse() {
+        let repeat = Repeat {
+            iterations: IterationCount::Count(2.0),
+            direction: AnimationDirection::AlternateReverse,
+            ..Repeat::default()
+        };
+
+        assert_eq!(position(&repeat, 0), (0, 1.0));
+        assert_eq!(position(&repeat, 250), (0, 0.75));
+        assert_eq!(position(&repeat, 1250), (1, 0.25));
+        assert_eq!(position(&repeat, 2000), (1, 1.0));
+    }
+
+    #[test]
+    fn delay_is_not_progress() {
+        let repeat = Repeat {
+            iterations: IterationCount::Count(2.0),
+            delay: Duration::from_millis(500),
+            ..Repeat::default()
+        };
+
+        assert_eq!(
+            repeat.total_duration(DURATION),
+            Some(Duration::from_millis(2500))
+        );
+
+        // The first iteration holds its end during the delay
+        assert_eq!(position(&repeat, 1000), (0, 1.0));
+        assert_eq!(position(&repeat, 1250), (0, 1.0));
+        assert_eq!(position(&repeat, 1750), (1, 0.25));
+        assert_eq!(position(&repeat, 2500), (1, 1.0));
+    }
+}
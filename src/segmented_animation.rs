@@ -1,24 +1,33 @@
-use super::{Curve, Tween, Value};
-use freya::{
-    dioxus_core::Task,
-    hooks::{use_platform, UsePlatform},
-    prelude::{spawn, use_memo, use_signal, Memo, Readable, Signal, Writable},
+use super::{
+    callbacks::{use_callbacks, AnimationEvent, Callbacks, Lifecycle},
+    completion::Completer,
+    driver::{use_animation_driver, AnimationDriver, AnimationJob},
+    playhead::Playhead,
+    repeat::Position,
+    signals::{use_key_signals, KeySignals},
+    AnimationClock, AnimationDirection, AnimationOutcome, Completion, Curve, IterationCount,
+    MotionCurve, MotionDuration, MotionPreference, MotionTheme, Repeat, Tween, Value,
+};
+use freya::prelude::{
+    use_drop, use_hook, use_memo, use_signal, CopyValue, Memo, ReadOnlySignal, Readable, Signal,
+    Writable,
 };
 use indexmap::IndexMap;
-use std::{ops::Range, time::Instant};
+use std::{collections::HashSet, ops::Range, panic::Location, time::Duration};
 
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default, PartialEq)]
 pub struct Context {
-    tweens: IndexMap<String, Signal<Tween>>,
-    segments: IndexMap<Range<u64>, Signal<Segment>>,
-    duration: u64,
+    tweens: IndexMap<String, Tween>,
+    segments: IndexMap<Range<Duration>, Signal<Segment>>,
+    essential: HashSet<String>,
+    repeat: Repeat,
 }
 
 struct Segment {
     tween: String,
     value: Value,
     curve: Curve,
-    duration: u64,
+    duration: Duration,
 }
 
 impl Context {
@@ -26,23 +35,28 @@ impl Context {
         let value = value.into();
 
         self.tweens
-            .insert(key.into(), Signal::new(Tween::new(value.clone(), value)));
+            .insert(key.into(), Tween::new(value.clone(), value));
     }
 
     pub fn add_segment<K: Into<String>, V: Into<Value>>(
         &mut self,
         tween: K,
         value: V,
-        curve: Curve,
-        duration: u64,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
     ) {
+        let (curve, duration) = MotionTheme::with_current(|theme| {
+            (
+                theme.resolve_curve(&curve.into()),
+                theme.resolve_duration(&duration.into()),
+            )
+        });
         let last = self
             .segments
             .last()
             .map(|(key, _)| key.end)
             .unwrap_or_default();
 
-        self.duration += duration;
         self.segments.insert(
             last..(last + duration),
             Signal::new(Segment {
@@ -53,6 +67,92 @@ impl Context {
             }),
         );
     }
+
+    /// Marks a tween as essential motion, its segments are played in full
+    /// whatever the [`MotionPreference`].
+    pub fn set_essential<K: Into<String>>(&mut self, key: K) {
+        self.essential.insert(key.into());
+    }
+
+    /// Plays the segments `count` times, like the CSS
+    /// `animation-iteration-count`.
+    pub fn repeat(&mut self, count: impl Into<IterationCount>) {
+        self.repeat.iterations = count.into();
+    }
+
+    /// Plays every other iteration backwards, going back and forth.
+    pub fn alternate(&mut self) {
+        self.repeat.direction = AnimationDirection::Alternate;
+    }
+
+    pub fn direction(&mut self, direction: AnimationDirection) {
+        self.repeat.direction = direction;
+    }
+
+    /// Waits `delay` between two iterations.
+    pub fn repeat_delay(&mut self, delay: Duration) {
+        self.repeat.delay = delay;
+    }
+
+    /// Starts at `iteration`, `0.5` starting halfway through the first one.
+    pub fn iteration_start(&mut self, iteration: f32) {
+        self.repeat.iteration_start = iteration;
+    }
+}
+
+/// The segments of a played animation, kept once it stopped so that it can
+/// be reversed and sought through.
+struct Timeline {
+    /// The segments, laid out with the preference they're played with.
+    segments: Vec<(Range<Duration>, Signal<Segment>, MotionPreference)>,
+    /// When each tween starts and stops moving.
+    spans: IndexMap<String, Range<Duration>>,
+    /// The values of the tweens when the animation was played.
+    origins: IndexMap<String, Value>,
+    /// The duration of an iteration.
+    duration: Duration,
+    repeat: Repeat,
+    playhead: Playhead,
+}
+
+impl Timeline {
+    fn total_duration(&self) -> Option<Duration> {
+        self.repeat.total_duration(self.duration)
+    }
+
+    fn position(&self, elapsed: Duration) -> Position {
+        self.repeat.position(elapsed, self.duration)
+    }
+
+    /// The values of the tweens `elapsed` into the animation, every segment
+    /// being evaluated so that they're right whichever way it plays.
+    fn values(&self, elapsed: Duration) -> IndexMap<String, Value> {
+        let time = self.duration.mul_f64(self.position(elapsed).progress);
+
+        values_at(&self.segments, &self.origins, time)
+    }
+}
+
+/// Where to move a [`SegmentedAnimation`] with [`SegmentedAnimation::seek`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Seek {
+    /// A time of the animation, all its iterations included.
+    Time(Duration),
+    /// A progress from `0.0` to `1.0`, through the iterations of the
+    /// animation, or through the first one if it repeats forever.
+    Progress(f32),
+}
+
+impl From<Duration> for Seek {
+    fn from(time: Duration) -> Self {
+        Self::Time(time)
+    }
+}
+
+impl From<f32> for Seek {
+    fn from(progress: f32) -> Self {
+        Self::Progress(progress)
+    }
 }
 
 /// So basic idea is:
@@ -62,10 +162,18 @@ impl Context {
 #[derive(PartialEq, Clone, Copy)]
 pub struct SegmentedAnimation {
     context: Memo<Context>,
+    tweens: Signal<IndexMap<String, Tween>>,
+    signals: KeySignals,
+    callbacks: Callbacks,
     is_running: Signal<bool>,
     has_run_yet: Signal<bool>,
-    platform: UsePlatform,
-    task: Signal<Option<Task>>,
+    driver: AnimationDriver,
+    job: Signal<Option<AnimationJob>>,
+    completer: Signal<Option<Completer>>,
+    timeline: Signal<Option<Timeline>>,
+    /// The time of the timeline on the last frame, across its iterations.
+    /// Frames update it along with the tweens, without notifying anyone else.
+    elapsed: CopyValue<Duration>,
 }
 
 impl SegmentedAnimation {
@@ -81,43 +189,349 @@ impl SegmentedAnimation {
 
     #[must_use]
     pub fn get<T: From<Value>>(&self, key: impl AsRef<str>) -> T {
-        self.context
+        self.tweens
             .read()
-            .tweens
             .get(key.as_ref())
             .unwrap()
-            .read()
             .value
             .clone()
             .into()
     }
 
-    pub fn play(&self) {
+    /// A signal following the value of `key`, which only notifies its
+    /// subscribers when that value changes. Pass it down to child components
+    /// so that only they re-render while the animation plays. It keeps its
+    /// last value if the tween is removed when the animation is rebuilt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no tween named `key`.
+    #[must_use]
+    pub fn signal<V: From<Value> + PartialEq + 'static>(
+        &self,
+        key: impl AsRef<str>,
+    ) -> ReadOnlySignal<V> {
+        let tweens = self.tweens;
+
+        self.signals.get(key.as_ref(), move |key| {
+            tweens.read().get(key).map(|tween| tween.value.clone())
+        })
+    }
+
+    /// Calls `callback` with the [`AnimationEvent`]s of the animations played
+    /// by the handle. Registering a callback again from the same place
+    /// replaces it, so this can be called while rendering.
+    #[track_caller]
+    pub fn on_event(&self, callback: impl FnMut(AnimationEvent) + 'static) {
+        self.callbacks.register(Location::caller(), None, callback);
+    }
+
+    /// Same as [`SegmentedAnimation::on_event`], for the animations of `key`.
+    #[track_caller]
+    pub fn on_key_event(
+        &self,
+        key: impl Into<String>,
+        callback: impl FnMut(AnimationEvent) + 'static,
+    ) {
+        self.callbacks
+            .register(Location::caller(), Some(key.into()), callback);
+    }
+
+    pub fn play(&self) -> Completion {
         self.run()
     }
 
-    pub fn cancel(&self) {
-        let mut task = self.task;
+    /// Plays the animation backwards, from where it is to its start. The
+    /// segments are played in reverse order with reversed curves, so the
+    /// tweens retrace the way they came.
+    pub fn reverse(&self) -> Completion {
+        let clock = AnimationClock::current();
+        let mut timeline = self.timeline;
+
+        match &mut *timeline.write() {
+            Some(timeline) => {
+                timeline.playhead.reverse(clock.now(), *self.elapsed.peek());
+            }
+            None => return Completer::new().1,
+        }
+
+        self.start(clock)
+    }
+
+    /// Stops the animation where it is, until [`SegmentedAnimation::resume`]
+    /// is called. Its completion stays pending.
+    pub fn pause(&self) {
+        let clock = AnimationClock::current();
+        let mut timeline = self.timeline;
+
+        if self.job.peek().is_none() {
+            return;
+        }
+
+        if let Some(timeline) = &mut *timeline.write() {
+            let total = timeline.total_duration();
+            let time = timeline
+                .playhead
+                .time(clock.now(), total, MotionPreference::Full);
+
+            timeline.playhead.pause(time);
+        }
+
+        self.suspend_when_still();
+    }
+
+    /// Plays the animation again from where it was paused or sought to.
+    pub fn resume(&self) {
+        let clock = AnimationClock::current();
+        let mut timeline = self.timeline;
+
+        let is_paused = match &mut *timeline.write() {
+            Some(timeline) if timeline.playhead.is_paused() => {
+                timeline.playhead.resume(clock.now());
+
+                true
+            }
+            _ => false,
+        };
+
+        // Sought to before being played
+        if is_paused && self.job.peek().is_none() {
+            self.start(clock);
+        } else {
+            self.suspend_when_still();
+        }
+    }
 
-        if let Some(task) = task.write().take() {
-            task.cancel();
+    /// Changes the pace of the animation from where it is: `2.0` plays it
+    /// twice as fast, `-1.0` backwards and `0.0` holds it. Playing it again
+    /// resets the rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` isn't finite.
+    pub fn set_rate(&self, rate: f32) {
+        assert!(rate.is_finite(), "expected a finite rate, got {rate}");
+
+        let now = AnimationClock::current().now();
+        let mut timeline = self.timeline;
+        let mut elapsed = self.elapsed;
+
+        if let Some(timeline) = &mut *timeline.write() {
+            let time =
+                timeline
+                    .playhead
+                    .time(now, timeline.total_duration(), MotionPreference::Full);
+
+            timeline.playhead.set_rate(now, time, rate);
+            elapsed.set(time);
+        }
+
+        self.suspend_when_still();
+    }
+
+    /// Stops requesting frames while the playhead doesn't move, and requests
+    /// them again once it does.
+    fn suspend_when_still(&self) {
+        let Some(job) = *self.job.peek() else {
+            return;
         };
+
+        let is_moving = self
+            .timeline
+            .peek()
+            .as_ref()
+            .is_some_and(|timeline| timeline.playhead.is_moving());
+
+        if is_moving {
+            self.driver.wake(job);
+        } else {
+            self.driver.suspend(job);
+        }
     }
 
-    fn run(&self) {
+    /// Moves the animation to `position`, a [`Duration`] or a progress from
+    /// `0.0` to `1.0`, and updates the tweens right away. Every segment is
+    /// evaluated, so the tweens of the segments which ended before it hold
+    /// their final values. Unless it's playing, the animation stays paused
+    /// there, which makes it possible to scrub through it.
+    pub fn seek(&self, position: impl Into<Seek>) {
+        let clock = AnimationClock::current();
+        let now = clock.now();
+        let mut timeline = self.timeline;
+        let mut elapsed = self.elapsed;
+        let tweens = self.tweens;
+
+        if timeline.peek().is_none() {
+            let mut playhead = Playhead::new(now);
+
+            playhead.pause(Duration::ZERO);
+
+            timeline.set(Some(self.lay_out(playhead)));
+        }
+
+        let is_playing = self.job.peek().is_some();
+        let values = timeline.write().as_mut().map(|timeline| {
+            let time = match (position.into(), timeline.total_duration()) {
+                (Seek::Time(time), Some(total)) => time.min(total),
+                (Seek::Time(time), None) => time,
+                (Seek::Progress(progress), Some(total)) => total.mul_f32(progress.clamp(0.0, 1.0)),
+                // The progress of the first iteration
+                (Seek::Progress(progress), None) => timeline.duration.mul_f32(progress.max(0.0)),
+            };
+
+            timeline.playhead.seek(now, time);
+
+            if !is_playing {
+                timeline.playhead.pause(time);
+            }
+
+            elapsed.set(time);
+            timeline.values(time)
+        });
+
+        if let Some(values) = values {
+            set_values(tweens, values);
+        }
+    }
+
+    /// Whether the animation is paused, by [`SegmentedAnimation::pause`] or
+    /// [`SegmentedAnimation::seek`].
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.timeline
+            .read()
+            .as_ref()
+            .is_some_and(|timeline| timeline.playhead.is_paused())
+    }
+
+    /// How far the animation is, from `0.0` to `1.0`. For an animation
+    /// repeating forever, it's how far the current iteration is.
+    ///
+    /// Read while rendering, the component renders again whenever a frame
+    /// moves the tweens.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        let elapsed = self.elapsed();
+
+        self.timeline.read().as_ref().map_or(0.0, |timeline| {
+            timeline.repeat.progress(elapsed, timeline.duration)
+        })
+    }
+
+    /// The time of the animation, all its iterations included. Like
+    /// [`SegmentedAnimation::progress`], it's only tracked through the tweens.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        // Frames update the elapsed time along with the tweens
+        drop(self.tweens.read());
+
+        *self.elapsed.peek()
+    }
+
+    pub fn cancel(&self) {
+        let mut job = self.job;
+        let mut completer = self.completer;
+
+        let previous = job.write().take();
+
+        // Cancelling sends the cancel events, outside of the signal
+        if let Some(previous) = previous {
+            self.driver.cancel(previous);
+        }
+
+        // Resolves the completion as cancelled
+        completer.write().take();
+    }
+
+    fn run(&self) -> Completion {
+        let clock = AnimationClock::current();
+        let mut timeline = self.timeline;
+        let mut elapsed = self.elapsed;
+
+        elapsed.set(Duration::ZERO);
+        timeline.set(Some(self.lay_out(Playhead::new(clock.now()))));
+
+        self.start(clock)
+    }
+
+    /// Lays the segments out again, shortening the non-essential ones, to be
+    /// played from the current values of the tweens.
+    fn lay_out(&self, playhead: Playhead) -> Timeline {
         let ctx = self.context.peek();
-        let platform = self.platform;
+        let preference = MotionPreference::current();
+        let mut duration = Duration::ZERO;
+
+        let segments = ctx
+            .segments
+            .values()
+            .map(|segment| {
+                let start = duration;
+                let (played, preference) = {
+                    let segment = segment.peek();
+                    let preference = if ctx.essential.contains(&segment.tween) {
+                        MotionPreference::Full
+                    } else {
+                        preference
+                    };
+
+                    (preference.duration(segment.duration), preference)
+                };
+
+                duration += played;
+
+                (start..duration, *segment, preference)
+            })
+            .collect::<Vec<_>>();
+        let mut spans = IndexMap::<String, Range<Duration>>::new();
+
+        for (key, segment, _) in &segments {
+            spans
+                .entry(segment.peek().tween.clone())
+                .and_modify(|span| span.end = key.end)
+                .or_insert_with(|| key.clone());
+        }
+
+        let origins = self
+            .tweens
+            .peek()
+            .iter()
+            .map(|(key, tween)| (key.clone(), tween.value.clone()))
+            .collect();
+
+        Timeline {
+            segments,
+            spans,
+            origins,
+            duration,
+            repeat: ctx.repeat,
+            playhead,
+        }
+    }
+
+    /// Plays the timeline from its playhead, replacing the previous
+    /// animation.
+    fn start(&self, clock: AnimationClock) -> Completion {
+        let driver = self.driver;
         let mut is_running = self.is_running;
-        let mut ticker = platform.new_ticker();
-        let segments = ctx.segments.clone();
-        let mut tweens = ctx.tweens.clone();
-        let duration = ctx.duration;
+        let tweens = self.tweens;
+        let mut timeline = self.timeline;
         let mut has_run_yet = self.has_run_yet;
-        let mut task = self.task;
+        let mut job = self.job;
+        let mut completer = self.completer;
+        let mut last_elapsed = self.elapsed;
+
+        // Cancel previous animations, outside of the signal as it sends the
+        // cancel events
+        let previous = job.write().take();
 
-        // Cancel previous animations
-        if let Some(task) = task.write().take() {
-            task.cancel();
+        if let Some(previous) = previous {
+            driver.cancel(previous);
+        }
+
+        let (animation_completer, completion) = Completer::new();
+
+        if let Some(previous) = completer.write().replace(animation_completer) {
+            previous.complete(AnimationOutcome::Interrupted);
         }
 
         if !self.peek_has_run_yet() {
@@ -126,82 +540,233 @@ impl SegmentedAnimation {
 
         is_running.set(true);
 
-        let animation_task = spawn(async move {
-            platform.request_animation_frame();
+        let (keys, mut iteration) =
+            timeline
+                .peek()
+                .as_ref()
+                .map_or_else(Default::default, |timeline| {
+                    (
+                        timeline.spans.keys().cloned().collect::<Vec<_>>(),
+                        timeline.position(*last_elapsed.peek()).iteration,
+                    )
+                });
+        let mut lifecycle = Lifecycle::start(self.callbacks, keys);
+
+        let animation_job = driver.register(move || {
+            let now = clock.now();
+
+            let Some(elapsed) = timeline.peek().as_ref().map(|timeline| {
+                timeline
+                    .playhead
+                    .time(now, timeline.total_duration(), MotionPreference::Full)
+            }) else {
+                return false;
+            };
+
+            // The readers of the progress are notified by the tweens
+            last_elapsed.set(elapsed);
+
+            let Some((values, position, key_progress, progress, is_finished)) =
+                timeline.peek().as_ref().map(|timeline| {
+                    let position = timeline.position(elapsed);
+                    let time = timeline.duration.mul_f64(position.progress);
+                    let is_reversed = timeline.playhead.is_reversed();
+                    let key_progress = timeline
+                        .spans
+                        .iter()
+                        .map(|(key, span)| {
+                            // Repeated tweens complete with the animation
+                            let is_done = timeline.repeat.is_once()
+                                && if is_reversed {
+                                    time <= span.start
+                                } else {
+                                    time >= span.end
+                                };
+
+                            (key.clone(), progress(time, span), is_done)
+                        })
+                        .collect::<Vec<_>>();
+
+                    (
+                        timeline.values(elapsed),
+                        position,
+                        key_progress,
+                        timeline.repeat.progress(elapsed, timeline.duration),
+                        timeline
+                            .playhead
+                            .is_done(elapsed, timeline.total_duration()),
+                    )
+                })
+            else {
+                return false;
+            };
+
+            set_values(tweens, values);
+
+            if position.iteration != iteration {
+                iteration = position.iteration;
+
+                lifecycle.iteration(iteration);
+
+                for (key, ..) in &key_progress {
+                    lifecycle.iteration_key(key, iteration);
+                }
+            }
 
-            let mut index = 0;
-            let mut prev_frame = Instant::now();
+            for (key, progress, is_done) in key_progress {
+                lifecycle.update_key(&key, progress);
 
-            loop {
-                // Wait for the event loop to tick
-                ticker.tick().await;
+                if is_done {
+                    lifecycle.complete_key(&key);
+                }
+            }
 
-                platform.request_animation_frame();
+            lifecycle.update(progress);
 
-                index += prev_frame.elapsed().as_millis();
+            if !is_finished {
+                return true;
+            }
 
-                // Advance the current segment
-                if let Some((key, current_segment)) = segments
-                    .iter()
-                    .find(|(key, _)| key.contains(&(index as u64)))
-                {
-                    let current_segment = current_segment.read();
+            is_running.set(false);
 
-                    if let Some(tween) = tweens.get_mut(&current_segment.tween) {
-                        let mut tween = tween.write();
+            job.write().take();
 
-                        if tween.curve != current_segment.curve {
-                            tween.curve = current_segment.curve.clone();
-                        }
+            if let Some(completer) = completer.write().take() {
+                completer.complete(AnimationOutcome::Completed);
+            }
 
-                        if tween.destination != current_segment.value {
-                            tween.to(current_segment.value.clone());
-                        }
+            lifecycle.complete();
 
-                        if tween.duration != current_segment.duration as f32 {
-                            tween.set_duration(current_segment.duration);
-                        }
+            false
+        });
 
-                        tween.advance((index as u64 - key.start) as f32);
-                    }
-                }
+        job.write().replace(animation_job);
 
-                prev_frame = Instant::now();
+        completion
+    }
+}
 
-                if index >= duration as u128 {
-                    break;
-                }
-            }
+/// The values of the tweens moved by `segments`, `time` after they started
+/// from `origins`.
+fn values_at(
+    segments: &[(Range<Duration>, Signal<Segment>, MotionPreference)],
+    origins: &IndexMap<String, Value>,
+    time: Duration,
+) -> IndexMap<String, Value> {
+    let mut values = IndexMap::new();
+
+    for (key, segment, preference) in segments {
+        // The segments play one after the other
+        if time < key.start {
+            break;
+        }
 
-            is_running.set(false);
+        let segment = segment.read();
+        let Some(origin) = values
+            .get(&segment.tween)
+            .or_else(|| origins.get(&segment.tween))
+        else {
+            continue;
+        };
 
-            task.write().take();
-        });
+        let value = if time < key.end {
+            let tween = Tween::new(origin.clone(), segment.value.clone())
+                .curve(segment.curve.clone())
+                .duration(segment.duration);
 
-        // Cancel previous animations
-        task.write().replace(animation_task);
+            // Shortened segments play faster
+            let time = (time - key.start)
+                .mul_f64(segment.duration.as_secs_f64() / (key.end - key.start).as_secs_f64());
+
+            preference.value_at(time, Some(segment.duration), |time| tween.value_at(time))
+        } else {
+            segment.value.clone()
+        };
+
+        values.insert(segment.tween.clone(), value);
+    }
+
+    values
+}
+
+/// Moves the tweens to `values`, notifying the subscribers once, and only
+/// when one of them moved.
+fn set_values(mut tweens: Signal<IndexMap<String, Tween>>, values: IndexMap<String, Value>) {
+    let moved = {
+        let tweens = tweens.peek();
+
+        values
+            .into_iter()
+            .filter(|(key, value)| tweens.get(key).is_some_and(|tween| tween.value != *value))
+            .collect::<Vec<_>>()
+    };
+
+    if moved.is_empty() {
+        return;
+    }
+
+    let mut tweens = tweens.write();
+
+    for (key, value) in moved {
+        if let Some(tween) = tweens.get_mut(&key) {
+            tween.value = value;
+        }
+    }
+}
+
+/// How far `elapsed` is through `span`, from `0.0` to `1.0`.
+fn progress(elapsed: Duration, span: &Range<Duration>) -> f32 {
+    if elapsed >= span.end {
+        1.0
+    } else if elapsed <= span.start {
+        0.0
+    } else {
+        (elapsed - span.start).div_duration_f32(span.end - span.start)
     }
 }
 
 pub fn use_segmented_animation(run: impl Fn(&mut Context) + 'static) -> SegmentedAnimation {
-    let platform = use_platform();
+    let driver = use_animation_driver();
     let is_running = use_signal(|| false);
     let has_run_yet = use_signal(|| false);
-    let task = use_signal(|| None);
+    let job = use_signal(|| None);
+    let completer = use_signal(|| None);
+    let timeline = use_signal(|| None);
+    let elapsed = use_hook(|| CopyValue::new(Duration::ZERO));
+    let mut tweens = use_signal(Default::default);
 
     let context = use_memo(move || {
         let mut context = Context::default();
 
         run(&mut context);
 
+        // The tweens start over from the rebuilt context
+        tweens.set(context.tweens.clone());
+
         context
     });
 
+    let signals = use_key_signals();
+    let callbacks = use_callbacks();
+
+    // The driver outlives the component, its animations mustn't.
+    use_drop(move || {
+        if let Some(job) = *job.peek() {
+            driver.cancel(job);
+        }
+    });
+
     SegmentedAnimation {
         context,
+        tweens,
+        signals,
+        callbacks,
         is_running,
         has_run_yet,
-        platform,
-        task,
+        driver,
+        job,
+        completer,
+        timeline,
+        elapsed,
     }
 }
@@ -0,0 +1,618 @@
+//! `serde` support, enabled by the `serde` feature.
+//!
+//! Everything is stored in a human-friendly way, so that it can be written by
+//! hand in configuration files: curves are stored by preset name or as CSS
+//! easing functions when possible, colors are stored as hex strings,
+//! gradients and shadows as CSS strings, and durations as milliseconds.
+
+use crate::{
+    curves::{
+        BackInCurve, BackInOutCurve, BackOutCurve, BounceInCurve, BounceInOutCurve, BounceOutCurve,
+        CatmullRom, ElasticInCurve, ElasticInOutCurve, ElasticOutCurve, Interpolation, Sampled,
+    },
+    Curve, IterationCount, MotionCurve, MotionDuration, MotionTheme, Repeat, Tween, Value,
+};
+use freya::prelude::Point2D;
+use freya_core::{
+    parsing::Parse,
+    values::{Fill, Shadow},
+};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use skia_safe::Color;
+use std::time::Duration;
+
+/// The most bounces a deserialized bounce curve can make, since every
+/// evaluation goes through all of them.
+const MAX_BOUNCES: usize = 16;
+
+/// Curves without a preset or a CSS equivalent.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CurveDefinition {
+    ThreePointCubic {
+        a1: (f32, f32),
+        b1: (f32, f32),
+        midpoint: (f32, f32),
+        a2: (f32, f32),
+        b2: (f32, f32),
+    },
+    Threshold {
+        threshold: f32,
+    },
+    SawTooth {
+        count: f32,
+    },
+    BackIn {
+        overshoot: f32,
+    },
+    BackOut {
+        overshoot: f32,
+    },
+    BackInOut {
+        overshoot: f32,
+    },
+    BounceIn {
+        bounces: usize,
+        restitution: f32,
+    },
+    BounceOut {
+        bounces: usize,
+        restitution: f32,
+    },
+    BounceInOut {
+        bounces: usize,
+        restitution: f32,
+    },
+    ElasticIn {
+        period: f32,
+        amplitude: f32,
+    },
+    ElasticOut {
+        period: f32,
+        amplitude: f32,
+    },
+    ElasticInOut {
+        period: f32,
+        amplitude: f32,
+    },
+    MonotoneCubic {
+        points: Vec<(f32, f32)>,
+    },
+    CatmullRom {
+        points: Vec<(f32, f32)>,
+        tension: f32,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CurveRepr {
+    Css(String),
+    Definition(CurveDefinition),
+}
+
+impl Serialize for Curve {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(name) = self.preset_name() {
+            return serializer.serialize_str(name);
+        }
+
+        if let Some(css) = self.to_css() {
+            return serializer.serialize_str(&css);
+        }
+
+        let definition = match self {
+            Self::ThreePointCubic(curve) => {
+                let [a1, b1, midpoint, a2, b2] = curve.points();
+
+                CurveDefinition::ThreePointCubic {
+                    a1,
+                    b1,
+                    midpoint,
+                    a2,
+                    b2,
+                }
+            }
+            Self::Threshold(curve) => CurveDefinition::Threshold {
+                threshold: curve.threshold(),
+            },
+            Self::SawTooth(curve) => CurveDefinition::SawTooth {
+                count: curve.count(),
+            },
+            Self::BackIn(curve) => CurveDefinition::BackIn {
+                overshoot: curve.overshoot(),
+            },
+            Self::BackOut(curve) => CurveDefinition::BackOut {
+                overshoot: curve.overshoot(),
+            },
+            Self::BackInOut(curve) => CurveDefinition::BackInOut {
+                overshoot: curve.overshoot(),
+            },
+            Self::BounceIn(curve) => CurveDefinition::BounceIn {
+                bounces: curve.bounces(),
+                restitution: curve.restitution(),
+            },
+            Self::BounceOut(curve) => CurveDefinition::BounceOut {
+                bounces: curve.bounces(),
+                restitution: curve.restitution(),
+            },
+            Self::BounceInOut(curve) => CurveDefinition::BounceInOut {
+                bounces: curve.bounces(),
+                restitution: curve.restitution(),
+            },
+            Self::ElasticIn(curve) => CurveDefinition::ElasticIn {
+                period: curve.period(),
+                amplitude: curve.amplitude(),
+            },
+            Self::ElasticOut(curve) => CurveDefinition::ElasticOut {
+                period: curve.period(),
+                amplitude: curve.amplitude(),
+            },
+            Self::ElasticInOut(curve) => CurveDefinition::ElasticInOut {
+                period: curve.period(),
+                amplitude: curve.amplitude(),
+            },
+            Self::Sampled(curve) => CurveDefinition::MonotoneCubic {
+                points: curve.points().to_vec(),
+            },
+            Self::CatmullRom(curve) => CurveDefinition::CatmullRom {
+                points: curve.control_points().to_vec(),
+                tension: curve.tension(),
+            },
+            // Presets and CSS easing functions, serialized above
+            Self::None
+            | Self::Linear(_)
+            | Self::Cubic(_)
+            | Self::Decelerate(_)
+            | Self::Stepped(_) => {
+                return Err(S::Error::custom(
+                    "expected a preset or a CSS easing function",
+                ))
+            }
+        };
+
+        definition.serialize(serializer)
+    }
+}
+
+fn check_restitution<E: serde::de::Error>(restitution: f32) -> Result<f32, E> {
+    if (0.0..=1.0).contains(&restitution) {
+        Ok(restitution)
+    } else {
+        Err(E::custom("expected restitution in 0..=1"))
+    }
+}
+
+fn check_bounces<E: serde::de::Error>(bounces: usize) -> Result<usize, E> {
+    if bounces <= MAX_BOUNCES {
+        Ok(bounces)
+    } else {
+        Err(E::custom(format!("expected at most {MAX_BOUNCES} bounces")))
+    }
+}
+
+fn check_period<E: serde::de::Error>(period: f32) -> Result<f32, E> {
+    if period > 0.0 && period.is_finite() {
+        Ok(period)
+    } else {
+        Err(E::custom("expected a positive period"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Curve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let definition = match CurveRepr::deserialize(deserializer)? {
+            CurveRepr::Css(css) => {
+                return Self::preset(&css)
+                    .or_else(|| Self::from_css(&css))
+                    .ok_or_else(|| D::Error::custom(format!("unknown curve `{css}`")));
+            }
+            CurveRepr::Definition(definition) => definition,
+        };
+
+        Ok(match definition {
+            CurveDefinition::ThreePointCubic {
+                a1,
+                b1,
+                midpoint,
+                a2,
+                b2,
+            } => {
+                if !(midpoint.0 > 0.0 && midpoint.0 < 1.0) {
+                    return Err(D::Error::custom(
+                        "expected midpoint to lie between 0.0 and 1.0",
+                    ));
+                }
+
+                Self::three_point_cubic(a1, b1, midpoint, a2, b2)
+            }
+            CurveDefinition::Threshold { threshold } => {
+                if !(0.0..=1.0).contains(&threshold) {
+                    return Err(D::Error::custom("expected threshold in 0..=1"));
+                }
+
+                Self::threshold(threshold)
+            }
+            CurveDefinition::SawTooth { count } => Self::saw_tooth(count),
+            CurveDefinition::BackIn { overshoot } => Self::BackIn(BackInCurve::new(overshoot)),
+            CurveDefinition::BackOut { overshoot } => Self::BackOut(BackOutCurve::new(overshoot)),
+            CurveDefinition::BackInOut { overshoot } => {
+                Self::BackInOut(BackInOutCurve::new(overshoot))
+            }
+            CurveDefinition::BounceIn {
+                bounces,
+                restitution,
+            } => Self::BounceIn(BounceInCurve::new(
+                check_bounces(bounces)?,
+                check_restitution(restitution)?,
+            )),
+            CurveDefinition::BounceOut {
+                bounces,
+                restitution,
+            } => Self::BounceOut(BounceOutCurve::new(
+                check_bounces(bounces)?,
+                check_restitution(restitution)?,
+            )),
+            CurveDefinition::BounceInOut {
+                bounces,
+                restitution,
+            } => Self::BounceInOut(BounceInOutCurve::new(
+                check_bounces(bounces)?,
+                check_restitution(restitution)?,
+            )),
+            CurveDefinition::ElasticIn { period, amplitude } => {
+                Self::ElasticIn(ElasticInCurve::new(check_period(period)?, amplitude))
+            }
+            CurveDefinition::ElasticOut { period, amplitude } => {
+                Self::ElasticOut(ElasticOutCurve::new(check_period(period)?, amplitude))
+            }
+            CurveDefinition::ElasticInOut { period, amplitude } => {
+                Self::ElasticInOut(ElasticInOutCurve::new(check_period(period)?, amplitude))
+            }
+            CurveDefinition::MonotoneCubic { points } => Self::Sampled(
+                Sampled::try_new(points, Interpolation::MonotoneCubic).map_err(D::Error::custom)?,
+            ),
+            CurveDefinition::CatmullRom { points, tension } => {
+                Self::CatmullRom(CatmullRom::try_new(points, tension).map_err(D::Error::custom)?)
+            }
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ValueRepr {
+    Number(f32),
+    Point((f32, f32)),
+    Text(String),
+}
+
+fn to_hex(color: Color) -> String {
+    if color.a() == u8::MAX {
+        format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+    } else {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            color.r(),
+            color.g(),
+            color.b(),
+            color.a()
+        )
+    }
+}
+
+fn from_hex(hex: &str) -> Option<Color> {
+    let channel = |index: usize| u8::from_str_radix(hex.get(index * 2..index * 2 + 2)?, 16).ok();
+
+    match hex.len() {
+        6 => Some(Color::from_rgb(channel(0)?, channel(1)?, channel(2)?)),
+        8 => Some(Color::from_argb(
+            channel(3)?,
+            channel(0)?,
+            channel(1)?,
+            channel(2)?,
+        )),
+        _ => None,
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Number(number) => serializer.serialize_f32(*number),
+            Self::Point(point) => (point.x, point.y).serialize(serializer),
+            Self::Color(color) => serializer.serialize_str(&to_hex(*color)),
+            Self::Gradient(gradient) => serializer.serialize_str(gradient),
+            Self::Shadow(shadow) if matches!(shadow.fill, Fill::Color(_)) => {
+                serializer.serialize_str(&String::from(self.clone()))
+            }
+            Self::Shadow(_) => Err(S::Error::custom(
+                "only shadows filled with a color can be serialized",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ValueRepr::deserialize(deserializer)? {
+            ValueRepr::Number(number) => Ok(Self::Number(number)),
+            ValueRepr::Point((x, y)) => Ok(Self::Point(Point2D::new(x, y))),
+            ValueRepr::Text(text) => {
+                if let Some(hex) = text.strip_prefix('#') {
+                    from_hex(hex)
+                        .map(Self::Color)
+                        .ok_or_else(|| D::Error::custom(format!("invalid hex color `{text}`")))
+                } else if text.contains("-gradient(") {
+                    Ok(Self::Gradient(text))
+                } else {
+                    Color::parse(&text)
+                        .map(Self::Color)
+                        .or_else(|_| Shadow::parse(&text).map(Self::Shadow))
+                        .map_err(|_| D::Error::custom(format!("invalid value `{text}`")))
+                }
+            }
+        }
+    }
+}
+
+/// Stores a [`Duration`] as a number of milliseconds, for `#[serde(with)]`.
+pub(crate) mod millis {
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let millis = f64::deserialize(deserializer)?;
+
+        Duration::try_from_secs_f64(millis / 1000.0)
+            .map_err(|_| D::Error::custom(format!("invalid duration `{millis}`")))
+    }
+}
+
+/// A stored [`Tween`], which starts at its origin once loaded.
+#[derive(Deserialize)]
+pub(crate) struct TweenRepr {
+    origin: Value,
+    destination: Value,
+    #[serde(with = "millis")]
+    duration: Duration,
+    #[serde(with = "millis")]
+    delay: Duration,
+    curve: Curve,
+    #[serde(default)]
+    repeat: Repeat,
+}
+
+impl From<TweenRepr> for Tween {
+    fn from(tween: TweenRepr) -> Self {
+        Self {
+            value: tween.origin.clone(),
+            origin: tween.origin,
+            destination: tween.destination,
+            duration: tween.duration,
+            delay: tween.delay,
+            curve: tween.curve,
+            repeat: tween.repeat,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MotionDurationRepr {
+    Millis(f64),
+    Token(String),
+}
+
+impl Serialize for MotionDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Duration(duration) => millis::serialize(duration, serializer),
+            Self::Token(token) => serializer.serialize_str(token),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MotionDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match MotionDurationRepr::deserialize(deserializer)? {
+            MotionDurationRepr::Millis(millis) => Duration::try_from_secs_f64(millis / 1000.0)
+                .map(Self::Duration)
+                .map_err(|_| D::Error::custom(format!("invalid duration `{millis}`"))),
+            MotionDurationRepr::Token(token) => Ok(Self::Token(token)),
+        }
+    }
+}
+
+/// Deserializes a [`MotionCurve`], rejecting tokens the current
+/// [`MotionTheme`] doesn't define, for `#[serde(deserialize_with)]`.
+pub(crate) fn known_curve<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<MotionCurve, D::Error> {
+    let curve = MotionCurve::deserialize(deserializer)?;
+
+    match &curve {
+        MotionCurve::Token(token)
+            if MotionTheme::with_current(|theme| theme.try_resolve_curve(&curve)).is_none() =>
+        {
+            Err(D::Error::custom(format!("unknown curve token `{token}`")))
+        }
+        _ => Ok(curve),
+    }
+}
+
+/// Same as [`known_curve`], for a [`MotionDuration`].
+pub(crate) fn known_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<MotionDuration, D::Error> {
+    let duration = MotionDuration::deserialize(deserializer)?;
+
+    match &duration {
+        MotionDuration::Token(token)
+            if MotionTheme::with_current(|theme| theme.try_resolve_duration(&duration))
+                .is_none() =>
+        {
+            Err(D::Error::custom(format!(
+                "unknown duration token `{token}`"
+            )))
+        }
+        _ => Ok(duration),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IterationCountRepr {
+    Count(f32),
+    Keyword(String),
+}
+
+impl Serialize for IterationCount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Count(count) => serializer.serialize_f32(*count),
+            Self::Infinite => serializer.serialize_str("infinite"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IterationCount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match IterationCountRepr::deserialize(deserializer)? {
+            IterationCountRepr::Count(count) if count >= 0.0 => Ok(Self::Count(count)),
+            IterationCountRepr::Keyword(keyword) if keyword == "infinite" => Ok(Self::Infinite),
+            IterationCountRepr::Count(count) => Err(D::Error::custom(format!(
+                "invalid iteration count `{count}`"
+            ))),
+            IterationCountRepr::Keyword(keyword) => Err(D::Error::custom(format!(
+                "invalid iteration count `{keyword}`"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        curves::{Interpolation, StepPosition},
+        Curve, MotionDuration, Tween, Value,
+    };
+    use freya::prelude::Point2D;
+    use skia_safe::Color;
+    use std::time::Duration;
+
+    fn round_trip_curve(curve: &Curve) -> (String, Curve) {
+        let json = serde_json::to_string(curve).unwrap();
+
+        (json.clone(), serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn curves() {
+        for (json, curve) in [
+            (r#""ease-in-out-back""#, Curve::EASE_IN_OUT_BACK),
+            (
+                r#""cubic-bezier(0.1, 0.7, 1, 0.1)""#,
+                Curve::cubic(0.1, 0.7, 1.0, 0.1),
+            ),
+            (
+                r#""steps(3, jump-both)""#,
+                Curve::steps(3, StepPosition::JumpBoth),
+            ),
+            (
+                r#"{"type":"bounce_out","bounces":2,"restitution":0.25}"#,
+                Curve::bounce_out(2, 0.25),
+            ),
+            (
+                r#"{"type":"elastic_in","period":0.3,"amplitude":1.5}"#,
+                Curve::elastic_in(0.3, 1.5),
+            ),
+            (
+                r#"{"type":"monotone_cubic","points":[[0.0,0.0],[0.5,0.8],[1.0,1.0]]}"#,
+                Curve::sampled(
+                    [(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)],
+                    Interpolation::MonotoneCubic,
+                ),
+            ),
+        ] {
+            let (serialized, deserialized) = round_trip_curve(&curve);
+
+            assert_eq!(serialized, json);
+            assert!(deserialized == curve, "{json}");
+        }
+    }
+
+    #[test]
+    fn invalid_curves() {
+        for json in [
+            r#""bounce""#,
+            r#"{"type":"bounce_in","bounces":17,"restitution":0.5}"#,
+            r#"{"type":"bounce_in","bounces":3,"restitution":1.5}"#,
+            r#"{"type":"elastic_out","period":0.0,"amplitude":1.0}"#,
+            r#"{"type":"threshold","threshold":2.0}"#,
+        ] {
+            assert!(serde_json::from_str::<Curve>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn values() {
+        for (json, value) in [
+            ("0.5", Value::Number(0.5)),
+            ("[1.0,2.0]", Value::Point(Point2D::new(1.0, 2.0))),
+            (
+                r##""#123456""##,
+                Value::Color(Color::from_rgb(0x12, 0x34, 0x56)),
+            ),
+            (
+                r##""#12345680""##,
+                Value::Color(Color::from_argb(0x80, 0x12, 0x34, 0x56)),
+            ),
+            (
+                r#""linear-gradient(0deg, red 0%, blue 100%)""#,
+                Value::Gradient(String::from("linear-gradient(0deg, red 0%, blue 100%)")),
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Value>(json).unwrap(), value);
+        }
+
+        assert!(serde_json::from_str::<Value>(r##""#12345""##).is_err());
+    }
+
+    #[test]
+    fn motion_durations() {
+        for (json, duration) in [
+            (
+                "250.0",
+                MotionDuration::Duration(Duration::from_millis(250)),
+            ),
+            (r#""medium""#, MotionDuration::Token(String::from("medium"))),
+        ] {
+            assert_eq!(serde_json::to_string(&duration).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<MotionDuration>(json).unwrap(),
+                duration
+            );
+        }
+
+        assert!(serde_json::from_str::<MotionDuration>("-1.0").is_err());
+    }
+
+    #[test]
+    fn tweens_start_at_their_origin() {
+        let mut tween = Tween::new(Value::Number(0.0), Value::Number(1.0));
+
+        tween.value = Value::Number(0.5);
+
+        let json = serde_json::to_string(&tween).unwrap();
+        let tween: Tween = serde_json::from_str(&json).unwrap();
+
+        assert!(!json.contains("\"value\""));
+        assert_eq!(tween.value, Value::Number(0.0));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::Value;
+use freya::prelude::{
+    current_scope_id, use_hook, CopyValue, Memo, ReadOnlySignal, Readable, ScopeId, Writable,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+/// The per-key signals handed out by an animation hook.
+///
+/// They're created once per key and type, in the scope of the hook, so that
+/// they can be passed down to child components and live as long as the
+/// animation.
+///
+/// Every one of them is a memo reading the whole map of tweens, so a frame
+/// reruns all of them. That's a lookup and a comparison each, and only the
+/// readers of the values which changed are notified.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct KeySignals {
+    scope: ScopeId,
+    signals: CopyValue<HashMap<(String, TypeId), Box<dyn Any>>>,
+}
+
+impl KeySignals {
+    /// The signal of `key`, following `value(key)`. It keeps its last value
+    /// once `value(key)` returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value(key)` returns `None` when the signal is created.
+    pub(crate) fn get<T: From<Value> + PartialEq + 'static>(
+        &self,
+        key: &str,
+        value: impl Fn(&str) -> Option<Value> + 'static,
+    ) -> ReadOnlySignal<T> {
+        let mut signals = self.signals;
+        let id = (key.to_owned(), TypeId::of::<T>());
+
+        if let Some(signal) = signals.peek().get(&id) {
+            return *signal.downcast_ref::<ReadOnlySignal<T>>().unwrap();
+        }
+
+        let key = id.0.clone();
+        let mut last = None;
+        let signal = self.scope.in_runtime(|| {
+            ReadOnlySignal::from(Memo::new(move || {
+                if let Some(value) = value(&key) {
+                    last = Some(value);
+                }
+
+                last.clone()
+                    .unwrap_or_else(|| panic!("failed to get tween with {key} name"))
+                    .into()
+            }))
+        });
+
+        signals.write().insert(id, Box::new(signal));
+
+        signal
+    }
+}
+
+pub(crate) fn use_key_signals() -> KeySignals {
+    use_hook(|| KeySignals {
+        scope: current_scope_id().unwrap(),
+        signals: CopyValue::new(HashMap::new()),
+    })
+}
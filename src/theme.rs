@@ -0,0 +1,282 @@
+use crate::Curve;
+use freya::prelude::{try_consume_context, use_context_provider, Readable, Signal};
+use indexmap::IndexMap;
+use std::{sync::LazyLock, time::Duration};
+
+/// A duration, either given directly or as the name of a token of the
+/// current [`MotionTheme`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MotionDuration {
+    Duration(Duration),
+    Token(String),
+}
+
+impl From<Duration> for MotionDuration {
+    fn from(duration: Duration) -> Self {
+        Self::Duration(duration)
+    }
+}
+
+impl From<&str> for MotionDuration {
+    fn from(token: &str) -> Self {
+        Self::Token(token.to_owned())
+    }
+}
+
+impl From<String> for MotionDuration {
+    fn from(token: String) -> Self {
+        Self::Token(token)
+    }
+}
+
+/// A curve, either given directly or as the name of a token of the current
+/// [`MotionTheme`].
+///
+/// Names which aren't tokens of the theme are looked up in
+/// [`Curve::PRESETS`].
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum MotionCurve {
+    Curve(Curve),
+    Token(String),
+}
+
+impl From<Curve> for MotionCurve {
+    fn from(curve: Curve) -> Self {
+        Self::Curve(curve)
+    }
+}
+
+impl From<&str> for MotionCurve {
+    fn from(token: &str) -> Self {
+        Self::Token(token.to_owned())
+    }
+}
+
+impl From<String> for MotionCurve {
+    fn from(token: String) -> Self {
+        Self::Token(token)
+    }
+}
+
+/// Named durations and curves, provided to the app with [`use_motion_theme`].
+///
+/// Animations built by `use_transition` and `use_segmented_animation` are
+/// rebuilt when the theme changes, so swapping it retimes the whole app.
+///
+/// Both built-in themes define the Material 3 tokens (`short1` to
+/// `extra-long4`, `emphasized`, `standard-decelerate`, ...), so that an app
+/// written against them can switch between the two.
+#[derive(Clone, PartialEq)]
+pub struct MotionTheme {
+    durations: IndexMap<String, Duration>,
+    curves: IndexMap<String, Curve>,
+}
+
+impl Default for MotionTheme {
+    fn default() -> Self {
+        Self::material3()
+    }
+}
+
+impl MotionTheme {
+    /// A theme without any token.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            durations: IndexMap::new(),
+            curves: IndexMap::new(),
+        }
+    }
+
+    /// The [Material 3](https://m3.material.io/styles/motion/easing-and-duration/tokens-specs)
+    /// duration and easing tokens.
+    #[must_use]
+    pub fn material3() -> Self {
+        Self::empty()
+            .with_duration("short1", Duration::from_millis(50))
+            .with_duration("short2", Duration::from_millis(100))
+            .with_duration("short3", Duration::from_millis(150))
+            .with_duration("short4", Duration::from_millis(200))
+            .with_duration("medium1", Duration::from_millis(250))
+            .with_duration("medium2", Duration::from_millis(300))
+            .with_duration("medium3", Duration::from_millis(350))
+            .with_duration("medium4", Duration::from_millis(400))
+            .with_duration("long1", Duration::from_millis(450))
+            .with_duration("long2", Duration::from_millis(500))
+            .with_duration("long3", Duration::from_millis(550))
+            .with_duration("long4", Duration::from_millis(600))
+            .with_duration("extra-long1", Duration::from_millis(700))
+            .with_duration("extra-long2", Duration::from_millis(800))
+            .with_duration("extra-long3", Duration::from_millis(900))
+            .with_duration("extra-long4", Duration::from_millis(1000))
+            .with_curve("emphasized", Curve::EASE_IN_OUT_CUBIC_EMPHASIZED)
+            .with_curve("emphasized-decelerate", Curve::cubic(0.05, 0.7, 0.1, 1.0))
+            .with_curve("emphasized-accelerate", Curve::cubic(0.3, 0.0, 0.8, 0.15))
+            .with_curve("standard", Curve::cubic(0.2, 0.0, 0.0, 1.0))
+            .with_curve("standard-decelerate", Curve::cubic(0.0, 0.0, 0.0, 1.0))
+            .with_curve("standard-accelerate", Curve::cubic(0.3, 0.0, 1.0, 1.0))
+            .with_curve("linear", Curve::LINEAR)
+    }
+
+    /// The [Fluent 2](https://fluent2.microsoft.design/motion) duration and
+    /// easing tokens, along with the Material 3 tokens mapped to their closest
+    /// Fluent equivalent.
+    #[must_use]
+    pub fn fluent() -> Self {
+        let ultra_fast = Duration::from_millis(50);
+        let faster = Duration::from_millis(100);
+        let fast = Duration::from_millis(150);
+        let normal = Duration::from_millis(200);
+        let gentle = Duration::from_millis(250);
+        let slow = Duration::from_millis(300);
+        let slower = Duration::from_millis(400);
+        let ultra_slow = Duration::from_millis(500);
+
+        let accelerate_max = Curve::cubic(0.9, 0.1, 1.0, 0.2);
+        let accelerate_mid = Curve::cubic(1.0, 0.0, 1.0, 1.0);
+        let accelerate_min = Curve::cubic(0.8, 0.0, 0.78, 1.0);
+        let decelerate_max = Curve::cubic(0.1, 0.9, 0.2, 1.0);
+        let decelerate_mid = Curve::cubic(0.0, 0.0, 0.0, 1.0);
+        let decelerate_min = Curve::cubic(0.33, 0.0, 0.1, 1.0);
+        let easy_ease_max = Curve::cubic(0.8, 0.0, 0.2, 1.0);
+        let easy_ease = Curve::cubic(0.33, 0.0, 0.67, 1.0);
+
+        Self::empty()
+            .with_duration("ultra-fast", ultra_fast)
+            .with_duration("faster", faster)
+            .with_duration("fast", fast)
+            .with_duration("normal", normal)
+            .with_duration("gentle", gentle)
+            .with_duration("slow", slow)
+            .with_duration("slower", slower)
+            .with_duration("ultra-slow", ultra_slow)
+            .with_duration("short1", ultra_fast)
+            .with_duration("short2", faster)
+            .with_duration("short3", fast)
+            .with_duration("short4", normal)
+            .with_duration("medium1", gentle)
+            .with_duration("medium2", slow)
+            .with_duration("medium3", slow)
+            .with_duration("medium4", slower)
+            .with_duration("long1", slower)
+            .with_duration("long2", ultra_slow)
+            .with_duration("long3", ultra_slow)
+            .with_duration("long4", ultra_slow)
+            .with_duration("extra-long1", ultra_slow)
+            .with_duration("extra-long2", ultra_slow)
+            .with_duration("extra-long3", ultra_slow)
+            .with_duration("extra-long4", ultra_slow)
+            .with_curve("accelerate-max", accelerate_max.clone())
+            .with_curve("accelerate-mid", accelerate_mid.clone())
+            .with_curve("accelerate-min", accelerate_min)
+            .with_curve("decelerate-max", decelerate_max.clone())
+            .with_curve("decelerate-mid", decelerate_mid.clone())
+            .with_curve("decelerate-min", decelerate_min)
+            .with_curve("easy-ease-max", easy_ease_max.clone())
+            .with_curve("easy-ease", easy_ease.clone())
+            .with_curve("linear", Curve::LINEAR)
+            .with_curve("emphasized", easy_ease_max)
+            .with_curve("emphasized-decelerate", decelerate_max)
+            .with_curve("emphasized-accelerate", accelerate_max)
+            .with_curve("standard", easy_ease)
+            .with_curve("standard-decelerate", decelerate_mid)
+            .with_curve("standard-accelerate", accelerate_mid)
+    }
+
+    /// Adds or replaces a duration token.
+    #[must_use]
+    pub fn with_duration(mut self, token: impl Into<String>, duration: Duration) -> Self {
+        self.durations.insert(token.into(), duration);
+
+        self
+    }
+
+    /// Adds or replaces a curve token.
+    #[must_use]
+    pub fn with_curve(mut self, token: impl Into<String>, curve: Curve) -> Self {
+        self.curves.insert(token.into(), curve);
+
+        self
+    }
+
+    #[must_use]
+    pub fn duration(&self, token: &str) -> Option<Duration> {
+        self.durations.get(token).copied()
+    }
+
+    #[must_use]
+    pub fn curve(&self, token: &str) -> Option<Curve> {
+        self.curves.get(token).cloned()
+    }
+
+    /// Resolves `duration`, or returns `None` if it's a token this theme
+    /// doesn't define.
+    #[must_use]
+    pub fn try_resolve_duration(&self, duration: &MotionDuration) -> Option<Duration> {
+        match duration {
+            MotionDuration::Duration(duration) => Some(*duration),
+            MotionDuration::Token(token) => self.duration(token),
+        }
+    }
+
+    /// Resolves `curve`, or returns `None` if it's a token this theme doesn't
+    /// define and isn't a curve preset either.
+    #[must_use]
+    pub fn try_resolve_curve(&self, curve: &MotionCurve) -> Option<Curve> {
+        match curve {
+            MotionCurve::Curve(curve) => Some(curve.clone()),
+            MotionCurve::Token(token) => self.curve(token).or_else(|| Curve::preset(token)),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the token isn't defined by this theme.
+    #[must_use]
+    pub fn resolve_duration(&self, duration: &MotionDuration) -> Duration {
+        match duration {
+            MotionDuration::Duration(duration) => *duration,
+            MotionDuration::Token(token) => self
+                .duration(token)
+                .unwrap_or_else(|| panic!("unknown duration token {token}")),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the token isn't defined by this theme nor is a curve preset.
+    #[must_use]
+    pub fn resolve_curve(&self, curve: &MotionCurve) -> Curve {
+        match curve {
+            MotionCurve::Curve(curve) => curve.clone(),
+            MotionCurve::Token(token) => self
+                .curve(token)
+                .or_else(|| Curve::preset(token))
+                .unwrap_or_else(|| panic!("unknown curve token {token}")),
+        }
+    }
+
+    /// Calls `f` with the theme provided by [`use_motion_theme`] in an
+    /// ancestor component, or the Material 3 theme if there's none.
+    ///
+    /// Called from within a memo, the memo is rerun when the theme changes.
+    pub fn with_current<R>(f: impl FnOnce(&Self) -> R) -> R {
+        static DEFAULT: LazyLock<MotionTheme> = LazyLock::new(MotionTheme::default);
+
+        match try_consume_context::<Signal<Self>>() {
+            Some(theme) => f(&theme.read()),
+            None => f(&DEFAULT),
+        }
+    }
+}
+
+/// Provides a [`MotionTheme`] to the descendants of the current component.
+/// Setting the returned signal swaps the theme.
+pub fn use_motion_theme(theme: impl FnOnce() -> MotionTheme) -> Signal<MotionTheme> {
+    use_context_provider(|| Signal::new(theme()))
+}
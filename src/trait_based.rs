@@ -1,15 +1,21 @@
-use crate::{Animation, Tween, Value};
-use freya::{
-    dioxus_core::Task,
-    hooks::{use_platform, UsePlatform},
-    prelude::{spawn, use_memo, use_signal, Memo, Readable, Signal, Writable},
+use crate::{
+    callbacks::{use_callbacks, AnimationEvent, Callbacks, Lifecycle},
+    completion::Completer,
+    driver::{use_animation_driver, AnimationDriver, AnimationJob},
+    playhead::Playhead,
+    signals::{use_key_signals, KeySignals},
+    Animation, AnimationClock, AnimationOutcome, Completion, MotionPreference, Tween, Value,
+};
+use freya::prelude::{
+    use_drop, use_memo, use_signal, Memo, ReadOnlySignal, Readable, Signal, Writable,
 };
 use indexmap::IndexMap;
-use std::time::Instant;
+use std::{collections::HashSet, panic::Location, time::Duration};
 
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default, PartialEq)]
 pub struct Context {
-    tweens: IndexMap<String, Signal<Tween>>,
+    tweens: IndexMap<String, Tween>,
+    essential: HashSet<String>,
 }
 
 impl Context {
@@ -17,17 +23,56 @@ impl Context {
         let value = value.into();
 
         self.tweens
-            .insert(key.into(), Signal::new(Tween::new(value.clone(), value)));
+            .insert(key.into(), Tween::new(value.clone(), value));
+    }
+
+    /// Marks a tween as essential motion, played in full whatever the
+    /// [`MotionPreference`].
+    pub fn set_essential<K: Into<String>>(&mut self, key: K) {
+        self.essential.insert(key.into());
     }
 }
 
+/// The last animation played on one of the tweens, kept once it stopped so
+/// that it can be reversed.
+struct Played {
+    animation: Box<dyn Animation>,
+    preference: MotionPreference,
+    playhead: Playhead,
+    /// The time of the animation on the last frame.
+    time: Duration,
+    iteration: u32,
+    /// Set while the animation is playing.
+    run: Option<Run>,
+}
+
+impl Played {
+    fn set_rate(&mut self, now: Duration, rate: f32) {
+        let time = self
+            .playhead
+            .time(now, self.animation.get_duration(), self.preference);
+
+        self.playhead.set_rate(now, time, rate);
+        self.time = time;
+    }
+}
+
+struct Run {
+    completer: Completer,
+    lifecycle: Lifecycle,
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub struct TraitBasedAnimation {
     context: Memo<Context>,
+    tweens: Signal<IndexMap<String, Tween>>,
+    signals: KeySignals,
+    callbacks: Callbacks,
     is_running: Signal<bool>,
     has_run_yet: Signal<bool>,
-    platform: UsePlatform,
-    tasks: Signal<IndexMap<String, Option<Task>>>,
+    driver: AnimationDriver,
+    played: Signal<IndexMap<String, Played>>,
+    job: Signal<Option<AnimationJob>>,
 }
 
 impl TraitBasedAnimation {
@@ -43,72 +88,230 @@ impl TraitBasedAnimation {
 
     #[must_use]
     pub fn get<V: From<Value>>(&self, key: impl AsRef<str>) -> V {
-        self.context
+        self.tweens
             .read()
-            .tweens
             .get(key.as_ref())
             .unwrap()
-            .read()
             .value
             .clone()
             .into()
     }
 
+    /// A signal following the value of `key`, which only notifies its
+    /// subscribers when that value changes. Pass it down to child components
+    /// so that only they re-render while the animation plays. It keeps its
+    /// last value if the tween is removed when the animation is rebuilt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no tween named `key`.
+    #[must_use]
+    pub fn signal<V: From<Value> + PartialEq + 'static>(
+        &self,
+        key: impl AsRef<str>,
+    ) -> ReadOnlySignal<V> {
+        let tweens = self.tweens;
+
+        self.signals.get(key.as_ref(), move |key| {
+            tweens.read().get(key).map(|tween| tween.value.clone())
+        })
+    }
+
+    /// Calls `callback` with the [`AnimationEvent`]s of the animations played
+    /// by the handle. Registering a callback again from the same place
+    /// replaces it, so this can be called while rendering. It gets one
+    /// [`AnimationEvent::Update`] per frame, with the progress of the least
+    /// advanced key.
+    #[track_caller]
+    pub fn on_event(&self, callback: impl FnMut(AnimationEvent) + 'static) {
+        self.callbacks.register(Location::caller(), None, callback);
+    }
+
+    /// Same as [`TraitBasedAnimation::on_event`], for the animations of `key`.
+    #[track_caller]
+    pub fn on_key_event(
+        &self,
+        key: impl Into<String>,
+        callback: impl FnMut(AnimationEvent) + 'static,
+    ) {
+        self.callbacks
+            .register(Location::caller(), Some(key.into()), callback);
+    }
+
     pub fn set<V: Into<Value>>(&mut self, key: impl AsRef<str>, value: V) {
-        let context = self.context.read();
-        let mut tween = *context.tweens.get(key.as_ref()).unwrap();
+        let mut tweens = self.tweens;
 
-        tween.write().set(value.into());
+        tweens
+            .write()
+            .get_mut(key.as_ref())
+            .unwrap()
+            .set(value.into());
     }
 
     pub fn play<T: Animation + 'static>(
         &self,
         key: impl AsRef<str> + Copy + 'static,
         animation: T,
-    ) {
+    ) -> Completion {
         self.run(key, animation)
     }
 
-    pub fn cancel(&self, key: impl AsRef<str>) {
-        let mut task = self.tasks;
+    /// Plays the animation of `key` backwards, from where it is to its
+    /// start. Its curves are reversed, so it retraces the way it came.
+    pub fn reverse(&self, key: impl AsRef<str>) -> Completion {
+        let key = key.as_ref();
+        let clock = AnimationClock::current();
+        let mut played = self.played;
+        let mut is_running = self.is_running;
+        let (completer, completion) = Completer::new();
+
+        if !played.peek().contains_key(key) {
+            return completion;
+        }
 
-        if let Some(task) = task
+        let previous = played
             .write()
-            .get_mut(key.as_ref())
-            .and_then(|task| task.take())
-        {
-            task.cancel();
+            .get_mut(key)
+            .and_then(|played| played.run.take());
+
+        // Sends the cancel events, outside of the map
+        if let Some(previous) = previous {
+            previous.completer.complete(AnimationOutcome::Interrupted);
+        }
+
+        let lifecycle = Lifecycle::start(self.callbacks, [key.to_owned()]);
+        let run = Run {
+            completer,
+            lifecycle,
         };
+
+        let rejected = match played.write().get_mut(key) {
+            Some(played) => {
+                played.playhead.reverse(clock.now(), played.time);
+                played.run.replace(run)
+            }
+            None => Some(run),
+        };
+
+        drop(rejected);
+
+        is_running.set(true);
+
+        if self.job.peek().is_none() {
+            self.start(clock);
+        } else {
+            self.suspend_when_still();
+        }
+
+        completion
     }
 
-    pub fn cancel_all(&self) {
-        let mut tasks = self.tasks;
+    /// Changes the pace of the animation of `key` without making it jump, a
+    /// negative `rate` playing it backwards. Playing another animation on
+    /// `key` resets it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` isn't finite.
+    pub fn set_rate(&self, key: impl AsRef<str>, rate: f32) {
+        assert!(rate.is_finite(), "expected a finite rate, got {rate}");
+
+        let now = AnimationClock::current().now();
+        let mut played = self.played;
+
+        if let Some(played) = played.write().get_mut(key.as_ref()) {
+            played.set_rate(now, rate);
+        }
 
-        for task in tasks.write().values_mut() {
-            if let Some(task) = task.take() {
-                task.cancel()
-            }
+        self.suspend_when_still();
+    }
+
+    /// Same as [`TraitBasedAnimation::set_rate`], for every animation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` isn't finite.
+    pub fn set_rate_all(&self, rate: f32) {
+        assert!(rate.is_finite(), "expected a finite rate, got {rate}");
+
+        let now = AnimationClock::current().now();
+        let mut played = self.played;
+
+        for played in played.write().values_mut() {
+            played.set_rate(now, rate);
         }
+
+        self.suspend_when_still();
     }
 
-    fn run<T: Animation + 'static>(&self, key: impl AsRef<str> + Copy + 'static, mut animation: T) {
-        let ctx = self.context.peek();
-        let platform = self.platform;
-        let mut is_running = self.is_running;
-        let mut ticker = platform.new_ticker();
-        let Some(mut tween) = ctx.tweens.get(key.as_ref()).cloned() else {
+    /// Stops requesting frames while none of the playing animations moves,
+    /// and requests them again once one does.
+    fn suspend_when_still(&self) {
+        let Some(job) = *self.job.peek() else {
             return;
         };
-        let mut has_run_yet = self.has_run_yet;
-        let mut task = self.tasks;
 
-        // Cancel previous animations
-        if let Some(task) = task
+        let is_moving = self
+            .played
+            .peek()
+            .values()
+            .any(|played| played.run.is_some() && played.playhead.is_moving());
+
+        if is_moving {
+            self.driver.wake(job);
+        } else {
+            self.driver.suspend(job);
+        }
+    }
+
+    pub fn cancel(&self, key: impl AsRef<str>) {
+        let mut played = self.played;
+        let cancelled = played
             .write()
             .get_mut(key.as_ref())
-            .and_then(|task| task.take())
-        {
-            task.cancel();
+            .and_then(|played| played.run.take());
+
+        // Sends the cancel events, outside of the map
+        drop(cancelled);
+    }
+
+    pub fn cancel_all(&self) {
+        let mut played = self.played;
+        let cancelled = played
+            .write()
+            .values_mut()
+            .filter_map(|played| played.run.take())
+            .collect::<Vec<_>>();
+
+        drop(cancelled);
+    }
+
+    fn run<T: Animation + 'static>(
+        &self,
+        key: impl AsRef<str> + Copy + 'static,
+        animation: T,
+    ) -> Completion {
+        let ctx = self.context.peek();
+        let mut tweens = self.tweens;
+        let clock = AnimationClock::current();
+        let preference = if ctx.essential.contains(key.as_ref()) {
+            MotionPreference::Full
+        } else {
+            MotionPreference::current()
+        };
+        let mut has_run_yet = self.has_run_yet;
+        let mut is_running = self.is_running;
+        let mut played = self.played;
+        let (completer, completion) = Completer::new();
+
+        if let Some(tween) = tweens.write().get_mut(key.as_ref()) {
+            animation.init(tween);
+
+            if let Some(duration) = animation.get_duration() {
+                tween.set_duration(duration);
+            }
+        } else {
+            return completion;
         }
 
         if !self.peek_has_run_yet() {
@@ -117,82 +320,215 @@ impl TraitBasedAnimation {
 
         is_running.set(true);
 
-        let animation_task = spawn(async move {
-            platform.request_animation_frame();
+        // Replaces the previous animation of the tween
+        let previous = played.write().shift_remove(key.as_ref());
+
+        if let Some(run) = previous.and_then(|previous| previous.run) {
+            run.completer.complete(AnimationOutcome::Interrupted);
+        }
 
-            let mut index = 0;
-            let mut prev_frame = Instant::now();
+        let lifecycle = Lifecycle::start(self.callbacks, [key.as_ref().to_owned()]);
+        let iteration = animation.get_iteration(Duration::ZERO);
+
+        let replaced = played.write().insert(
+            key.as_ref().to_owned(),
+            Played {
+                animation: Box::new(animation),
+                preference,
+                playhead: Playhead::new(clock.now()),
+                time: Duration::ZERO,
+                iteration,
+                run: Some(Run {
+                    completer,
+                    lifecycle,
+                }),
+            },
+        );
+
+        // Played again by a start callback
+        drop(replaced);
+
+        if self.job.peek().is_none() {
+            self.start(clock);
+        } else {
+            self.suspend_when_still();
+        }
 
-            animation.init(&mut tween.write());
+        completion
+    }
 
-            tween.write().set_duration(animation.get_duration());
+    /// Advances all the playing animations in a single job, so that a frame
+    /// notifies the subscribers of the tweens once.
+    fn start(&self, clock: AnimationClock) {
+        let mut tweens = self.tweens;
+        let callbacks = self.callbacks;
+        let mut played = self.played;
+        let mut is_running = self.is_running;
+        let mut job = self.job;
 
-            loop {
-                // Wait for the event loop to tick
-                ticker.tick().await;
+        let animation_job = self.driver.register(move || {
+            let now = clock.now();
+            let mut moved = Vec::new();
+            let mut progress = Vec::new();
+            let mut iterations = Vec::new();
 
-                platform.request_animation_frame();
+            for (key, current) in played.write().iter_mut() {
+                if current.run.is_none() {
+                    continue;
+                }
 
-                index += prev_frame.elapsed().as_millis();
+                let Some(mut tween) = tweens.peek().get(key).cloned() else {
+                    continue;
+                };
+                let duration = current.animation.get_duration();
+                let time = current.playhead.time(now, duration, current.preference);
 
                 // Advance the current segment
-                animation.advance(&mut tween.write(), index);
+                if current.preference == MotionPreference::Reduced {
+                    let animation = &mut current.animation;
+                    let value = current.preference.value_at(time, duration, |time| {
+                        let mut tween = tween.clone();
+
+                        animation.advance(&mut tween, time);
+
+                        tween.value
+                    });
 
-                // println!("{:?}", tween.read().value);
+                    tween.value = value;
+                } else {
+                    current.animation.advance(&mut tween, time);
+                }
+
+                if tweens.peek().get(key) != Some(&tween) {
+                    moved.push((key.clone(), tween));
+                }
+
+                current.time = time;
 
-                prev_frame = Instant::now();
+                let iteration = current.animation.get_iteration(time);
 
-                if index >= animation.get_duration() as u128 {
-                    break;
+                if iteration != current.iteration {
+                    current.iteration = iteration;
+
+                    iterations.push((key.clone(), iteration));
                 }
+
+                progress.push((
+                    key.clone(),
+                    current.animation.get_progress(time),
+                    current.playhead.is_done(time, duration),
+                ));
             }
 
-            tween.write().advance(animation.get_duration() as f32);
+            // Notify the subscribers only when something moved
+            if !moved.is_empty() {
+                let mut tweens = tweens.write();
 
-            task.write()
-                .get_mut(key.as_ref())
-                .and_then(|task| task.take());
+                for (key, tween) in moved {
+                    tweens.insert(key, tween);
+                }
+            }
 
-            if task.read().is_empty() {
-                is_running.set(false);
+            let mut finished = progress
+                .iter()
+                .filter(|(_, _, is_done)| *is_done)
+                .filter_map(|(key, _, _)| {
+                    played
+                        .write()
+                        .get_mut(key)
+                        .and_then(|played| played.run.take())
+                })
+                .collect::<Vec<_>>();
+
+            // The handle gets one event per frame: it follows its least advanced
+            // key, which finishes last
+            let latest_iteration = iterations.iter().map(|(_, iteration)| *iteration).max();
+            let animation_progress = progress
+                .iter()
+                .map(|(_, progress, _)| *progress)
+                .reduce(f32::min);
+
+            // The callbacks can play and cancel animations, so they're called
+            // outside of the map
+            for (key, iteration) in iterations {
+                callbacks.emit(Some(&key), AnimationEvent::Iteration(iteration));
             }
+
+            for (key, progress, _) in progress {
+                callbacks.emit(Some(&key), AnimationEvent::Update(progress));
+            }
+
+            if let Some(iteration) = latest_iteration {
+                callbacks.emit(None, AnimationEvent::Iteration(iteration));
+            }
+
+            if let Some(progress) = animation_progress {
+                callbacks.emit(None, AnimationEvent::Update(progress));
+            }
+
+            for finished in &mut finished {
+                finished.completer.complete(AnimationOutcome::Completed);
+                finished.lifecycle.complete();
+            }
+
+            if played.peek().values().any(|played| played.run.is_some()) {
+                return true;
+            }
+
+            is_running.set(false);
+
+            job.write().take();
+
+            false
         });
 
-        // Cancel previous animations
-        if let Some(task) = task.write().get_mut(key.as_ref()) {
-            task.replace(animation_task);
-        };
+        job.write().replace(animation_job);
     }
 }
 
 pub fn use_trait_animation(run: impl Fn(&mut Context) + 'static) -> TraitBasedAnimation {
-    let platform = use_platform();
+    let driver = use_animation_driver();
     let is_running = use_signal(|| false);
     let has_run_yet = use_signal(|| false);
+    let mut played = use_signal(IndexMap::new);
+    let job = use_signal(|| None);
+    let mut tweens = use_signal(Default::default);
 
     let context = use_memo(move || {
         let mut context = Context::default();
 
         run(&mut context);
 
+        // The tweens start over from the rebuilt context
+        tweens.set(context.tweens.clone());
+
         context
     });
 
-    let tasks = use_signal(|| {
-        context
-            .read()
-            .tweens
-            .keys()
-            .cloned()
-            .map(|key| (key, None))
-            .collect()
+    let signals = use_key_signals();
+    let callbacks = use_callbacks();
+
+    // The driver outlives the component, its animations mustn't.
+    use_drop(move || {
+        if let Some(job) = *job.peek() {
+            driver.cancel(job);
+        }
+
+        // Sends the cancel events while the callbacks are still there
+        let cancelled = std::mem::take(&mut *played.write());
+
+        drop(cancelled);
     });
 
     TraitBasedAnimation {
         context,
+        tweens,
+        signals,
+        callbacks,
         is_running,
         has_run_yet,
-        platform,
-        tasks,
+        driver,
+        played,
+        job,
     }
 }
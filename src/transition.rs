@@ -1,14 +1,32 @@
-use super::{Curve, Tween, Value};
+use super::{
+    callbacks::{use_callbacks, AnimationEvent, Callbacks, Lifecycle},
+    completion::Completer,
+    driver::{use_animation_driver, AnimationDriver, AnimationJob},
+    playhead::Playhead,
+    signals::{use_key_signals, KeySignals},
+    AnimationClock, AnimationOutcome, Completion, Curve, MotionCurve, MotionDuration,
+    MotionPreference, MotionTheme, Repeat, Tween, Value,
+};
 use freya::{
     dioxus_core::Task,
-    hooks::{use_platform, UsePlatform},
-    prelude::{spawn, use_memo, use_signal, Memo, Readable, Signal, Writable},
+    prelude::{
+        spawn, use_drop, use_memo, use_signal, Memo, ReadOnlySignal, Readable, Signal, Writable,
+    },
+};
+use indexmap::IndexMap;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    hash::Hash,
+    panic::Location,
+    rc::Rc,
+    time::Duration,
 };
-use std::{collections::HashMap, hash::Hash, time::Instant};
 
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default, PartialEq)]
 pub struct Context {
-    tweens: HashMap<String, Signal<Tween>>,
+    tweens: HashMap<String, Tween>,
+    essential: HashSet<String>,
 }
 
 impl Context {
@@ -16,104 +34,196 @@ impl Context {
         &mut self,
         key: K,
         value: V,
-        curve: Curve,
-        duration: u64,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
     ) {
         let value = value.into();
+        let tween = MotionTheme::with_current(|theme| {
+            Tween::new(value.clone(), value)
+                .curve(theme.resolve_curve(&curve.into()))
+                .duration(theme.resolve_duration(&duration.into()))
+        });
 
-        self.tweens.insert(
-            key.into(),
-            Signal::new(
-                Tween::new(value.clone(), value)
-                    .curve(curve)
-                    .duration(duration),
-            ),
-        );
+        self.tweens.insert(key.into(), tween);
     }
 
     pub fn add_tween_delayed<K: Into<String>, V: Into<Value>>(
         &mut self,
         key: K,
         value: V,
-        curve: Curve,
-        duration: u64,
-        delay: u64,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
+        delay: Duration,
     ) {
         let value = value.into();
+        let tween = MotionTheme::with_current(|theme| {
+            Tween::new(value.clone(), value)
+                .curve(theme.resolve_curve(&curve.into()))
+                .duration(theme.resolve_duration(&duration.into()))
+                .delay(delay)
+        });
 
-        self.tweens.insert(
-            key.into(),
-            Signal::new(
-                Tween::new(value.clone(), value)
-                    .curve(curve)
-                    .duration(duration)
-                    .delay(delay),
-            ),
-        );
+        self.tweens.insert(key.into(), tween);
+    }
+
+    /// Sets how the tween repeats, like [`Tween::repeat`] and
+    /// [`Tween::alternate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no tween named `key`.
+    pub fn set_repeat<K: AsRef<str>>(&mut self, key: K, repeat: Repeat) {
+        self.tweens
+            .get_mut(key.as_ref())
+            .unwrap_or_else(|| panic!("failed to get tween with {} name", key.as_ref()))
+            .repeat = repeat;
+    }
+
+    /// Marks a tween as essential motion, played in full whatever the
+    /// [`MotionPreference`].
+    pub fn set_essential<K: Into<String>>(&mut self, key: K) {
+        self.essential.insert(key.into());
     }
 }
 
+/// How a tween is played by [`Transition::play`] and
+/// [`Transition::reverse`].
+struct Track {
+    preference: MotionPreference,
+    playhead: Playhead,
+    /// The time of the tween on the last frame.
+    time: Duration,
+}
+
+/// An animation started by [`Transition::to`] or [`Transition::wait`].
+struct Step {
+    key: Option<String>,
+    completer: Rc<Completer>,
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub struct Transition {
     context: Memo<Context>,
+    tweens: Signal<HashMap<String, Tween>>,
+    signals: KeySignals,
+    callbacks: Callbacks,
     is_running: Signal<bool>,
     has_run_yet: Signal<bool>,
-    platform: UsePlatform,
-    task: Signal<Option<Task>>,
+    driver: AnimationDriver,
+    job: Signal<Option<AnimationJob>>,
+    completer: Signal<Option<Completer>>,
+    tracks: Signal<HashMap<String, Track>>,
+    steps: Signal<IndexMap<AnimationJob, Step>>,
+    script: Signal<Option<Task>>,
 }
 
 impl Transition {
     pub fn set<K: AsRef<str>, V: Into<Value>>(&self, key: K, value: V) {
-        let context = self.context.peek();
-        let mut tween = *context
-            .tweens
-            .get(key.as_ref())
+        let mut tweens = self.tweens;
+        let mut tweens = tweens.write();
+        let tween = tweens
+            .get_mut(key.as_ref())
             .unwrap_or_else(|| panic!("failed to get tween with {} name", key.as_ref()));
 
-        tween.write().to(value.into());
+        tween.to(value.into());
     }
 
-    pub fn set_duration<K: AsRef<str>>(&self, key: K, millis: u64) {
-        let context = self.context.peek();
-        let mut tween = *context.tweens.get(key.as_ref()).unwrap();
+    pub fn set_duration<K: AsRef<str>>(&self, key: K, duration: Duration) {
+        let mut tweens = self.tweens;
 
-        tween.write().set_duration(millis);
+        tweens
+            .write()
+            .get_mut(key.as_ref())
+            .unwrap()
+            .set_duration(duration);
     }
 
     pub fn set_curve<K: AsRef<str>>(&self, key: K, curve: Curve) {
-        let context = self.context.peek();
-        let mut tween = *context.tweens.get(key.as_ref()).unwrap();
+        let mut tweens = self.tweens;
+
+        tweens
+            .write()
+            .get_mut(key.as_ref())
+            .unwrap()
+            .set_curve(curve);
+    }
+
+    pub fn set_delay<K: AsRef<str>>(&self, key: K, delay: Duration) {
+        let mut tweens = self.tweens;
 
-        tween.write().set_curve(curve);
+        tweens
+            .write()
+            .get_mut(key.as_ref())
+            .unwrap()
+            .set_delay(delay);
     }
 
-    pub fn set_delay<K: AsRef<str>>(&self, key: K, millis: u64) {
-        let context = self.context.peek();
-        let mut tween = *context.tweens.get(key.as_ref()).unwrap();
+    pub fn set_repeat<K: AsRef<str>>(&self, key: K, repeat: Repeat) {
+        let mut tweens = self.tweens;
 
-        tween.write().set_delay(millis);
+        tweens.write().get_mut(key.as_ref()).unwrap().repeat = repeat;
     }
 
     pub fn forced_set<K: AsRef<str>, V: Into<Value>>(&self, key: K, value: V) {
-        let context = self.context.peek();
-        let mut tween = *context.tweens.get(key.as_ref()).unwrap();
+        let mut tweens = self.tweens;
 
-        tween.write().set(value.into());
+        tweens
+            .write()
+            .get_mut(key.as_ref())
+            .unwrap()
+            .set(value.into());
     }
 
     #[must_use]
     pub fn get<V: From<Value>>(&self, key: impl AsRef<str>) -> V {
-        self.context
+        self.tweens
             .read()
-            .tweens
             .get(key.as_ref())
             .unwrap()
-            .read()
             .value
             .clone()
             .into()
     }
 
+    /// A signal following the value of `key`, which only notifies its
+    /// subscribers when that value changes. Pass it down to child components
+    /// so that only they re-render while the animation plays. It keeps its
+    /// last value if the tween is removed when the animation is rebuilt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no tween named `key`.
+    #[must_use]
+    pub fn signal<V: From<Value> + PartialEq + 'static>(
+        &self,
+        key: impl AsRef<str>,
+    ) -> ReadOnlySignal<V> {
+        let tweens = self.tweens;
+
+        self.signals.get(key.as_ref(), move |key| {
+            tweens.read().get(key).map(|tween| tween.value.clone())
+        })
+    }
+
+    /// Calls `callback` with the [`AnimationEvent`]s of the animations played
+    /// by the handle. Registering a callback again from the same place
+    /// replaces it, so this can be called while rendering.
+    #[track_caller]
+    pub fn on_event(&self, callback: impl FnMut(AnimationEvent) + 'static) {
+        self.callbacks.register(Location::caller(), None, callback);
+    }
+
+    /// Same as [`Transition::on_event`], for the animations of `key`.
+    #[track_caller]
+    pub fn on_key_event(
+        &self,
+        key: impl Into<String>,
+        callback: impl FnMut(AnimationEvent) + 'static,
+    ) {
+        self.callbacks
+            .register(Location::caller(), Some(key.into()), callback);
+    }
+
     #[must_use]
     pub fn is_playing(&self) -> bool {
         *self.is_running.read()
@@ -124,53 +234,167 @@ impl Transition {
         *self.has_run_yet.peek()
     }
 
-    pub fn play_all(&self) {
+    pub fn play_all(&self) -> Completion {
         self.run::<_, _, Vec<(&str, f32)>>(None)
     }
 
     pub fn play<K: Into<String> + Hash + Eq, V: Into<Value>, I: IntoIterator<Item = (K, V)>>(
         &self,
         filter: I,
-    ) {
+    ) -> Completion {
         self.run(Some(filter))
     }
 
+    /// Cancels the played animation, as well as the running script and its
+    /// animations.
     pub fn cancel(&self) {
-        let mut task = self.task;
+        let mut job = self.job;
+        let mut completer = self.completer;
 
-        if let Some(task) = task.write().take() {
-            task.cancel();
-        };
+        let previous = job.write().take();
+
+        // Cancelling sends the cancel events, outside of the signal
+        if let Some(previous) = previous {
+            self.driver.cancel(previous);
+        }
+
+        // Resolves the completion as cancelled
+        completer.write().take();
+
+        self.cancel_script();
+    }
+
+    /// Plays the last played animation backwards, from where it is to
+    /// where it started. The curves are reversed, so the values retrace the
+    /// way they came.
+    pub fn reverse(&self) -> Completion {
+        let clock = AnimationClock::current();
+        let mut tracks = self.tracks;
+
+        if tracks.peek().is_empty() {
+            return Completer::new().1;
+        }
+
+        let now = clock.now();
+
+        for track in tracks.write().values_mut() {
+            track.playhead.reverse(now, track.time);
+        }
+
+        self.start(clock)
+    }
+
+    /// Plays the animation at `rate` times its pace until it's played again,
+    /// backwards if negative and without moving if zero. It carries on from
+    /// where it is, so the rate can follow a gesture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` isn't finite.
+    pub fn set_rate(&self, rate: f32) {
+        assert!(rate.is_finite(), "expected a finite rate, got {rate}");
+
+        let now = AnimationClock::current().now();
+        let tweens = self.tweens;
+        let mut tracks = self.tracks;
+
+        for (key, track) in tracks.write().iter_mut() {
+            let Some(tween) = tweens.peek().get(key).cloned() else {
+                continue;
+            };
+            let time = track
+                .playhead
+                .time(now, tween.total_duration(), track.preference);
+
+            track.playhead.set_rate(now, time, rate);
+            track.time = time;
+        }
+
+        // Held animations don't need frames
+        if let Some(job) = *self.job.peek() {
+            if rate == 0.0 {
+                self.driver.suspend(job);
+            } else {
+                self.driver.wake(job);
+            }
+        }
     }
 
     fn run<K: Into<String> + Hash + Eq, V: Into<Value>, I: IntoIterator<Item = (K, V)>>(
         &self,
         filter: Option<I>,
-    ) {
+    ) -> Completion {
         let ctx = self.context.peek();
-        let platform = self.platform;
-        let mut is_running = self.is_running;
-        let mut ticker = platform.new_ticker();
-        let mut values = ctx.tweens.clone();
-        let mut has_run_yet = self.has_run_yet;
-        let mut task = self.task;
+        let clock = AnimationClock::current();
+        let mut tweens = self.tweens;
+        let mut tracks = self.tracks;
+        let mut keys = tweens.peek().keys().cloned().collect::<HashSet<_>>();
+        let preference = MotionPreference::current();
 
         if let Some(filter) = filter {
-            let mut filter: HashMap<_, _> = filter
+            let mut filter: HashMap<String, Value> = filter
                 .into_iter()
                 .map(|(key, value)| (key.into(), value.into()))
                 .collect();
 
-            values.retain(|key, _| filter.contains_key(key));
+            keys.retain(|key| filter.contains_key(key));
+
+            // The steps of the played keys would fight with their tracks
+            self.interrupt_steps(|key| keys.contains(key));
 
-            for (key, value) in &mut values {
-                value.write().to(filter.remove(key).unwrap());
+            for (key, tween) in tweens.write().iter_mut() {
+                if let Some(value) = filter.remove(key) {
+                    tween.to(value);
+                }
             }
         }
 
-        // Cancel previous animations
-        if let Some(task) = task.write().take() {
-            task.cancel();
+        let now = clock.now();
+
+        tracks.set(
+            keys.into_iter()
+                .map(|key| {
+                    let track = Track {
+                        preference: if ctx.essential.contains(&key) {
+                            MotionPreference::Full
+                        } else {
+                            preference
+                        },
+                        playhead: Playhead::new(now),
+                        time: Duration::ZERO,
+                    };
+
+                    (key, track)
+                })
+                .collect(),
+        );
+
+        self.start(clock)
+    }
+
+    /// Plays the tracks from their playheads, replacing the previous
+    /// animation.
+    fn start(&self, clock: AnimationClock) -> Completion {
+        let driver = self.driver;
+        let mut is_running = self.is_running;
+        let mut tweens = self.tweens;
+        let mut tracks = self.tracks;
+        let mut has_run_yet = self.has_run_yet;
+        let mut job = self.job;
+        let mut completer = self.completer;
+
+        // Cancel previous animations, outside of the signal as it sends the
+        // cancel events
+        let previous = job.write().take();
+
+        if let Some(previous) = previous {
+            driver.cancel(previous);
+        }
+
+        let (animation_completer, completion) = Completer::new();
+
+        if let Some(previous) = completer.write().replace(animation_completer) {
+            previous.complete(AnimationOutcome::Interrupted);
         }
 
         if !self.peek_has_run_yet() {
@@ -179,63 +403,379 @@ impl Transition {
 
         is_running.set(true);
 
-        let animation_task = spawn(async move {
-            platform.request_animation_frame();
+        // The progress of the animation is the one of its longest tween, a
+        // tween repeating forever being the longest
+        let longest = {
+            let tweens = tweens.peek();
+
+            tracks
+                .peek()
+                .iter()
+                .filter_map(|(key, track)| {
+                    let tween = tweens.get(key)?;
+                    let length = match tween.total_duration() {
+                        Some(total) => (false, track.preference.duration(total)),
+                        None => (true, tween.duration + tween.repeat.delay),
+                    };
+
+                    Some((key.clone(), length))
+                })
+                .max_by_key(|(_, length)| *length)
+                .map(|(key, _)| key)
+        };
+        let mut iterations = {
+            let tweens = tweens.peek();
+
+            tracks
+                .peek()
+                .iter()
+                .filter_map(|(key, track)| {
+                    Some((key.clone(), tweens.get(key)?.iteration(track.time)))
+                })
+                .collect::<HashMap<_, _>>()
+        };
+        let mut iteration = iterations.values().copied().max().unwrap_or_default();
+        let mut lifecycle = Lifecycle::start(self.callbacks, tracks.peek().keys().cloned());
+
+        let animation_job = driver.register(move || {
+            let now = clock.now();
+
+            let mut is_finished = true;
+            let mut moved = Vec::new();
+            let mut progress = Vec::new();
+
+            // Advance the animations
+            for (key, track) in tracks.write().iter_mut() {
+                let tweens = tweens.peek();
+                let Some(tween) = tweens.get(key) else {
+                    continue;
+                };
+                let total = tween.total_duration();
+                let time = track.playhead.time(now, total, track.preference);
+                let is_done = track.playhead.is_done(time, total);
+                let value = track
+                    .preference
+                    .value_at(time, total, |time| tween.value_at(time));
+
+                if value != tween.value {
+                    moved.push((key.clone(), value));
+                }
+
+                track.time = time;
+
+                is_finished &= is_done;
+
+                progress.push((
+                    key.clone(),
+                    tween.progress(time),
+                    tween.iteration(time),
+                    is_done,
+                ));
+            }
+
+            // Notify the subscribers once per frame, and only when something moved
+            if !moved.is_empty() {
+                let mut tweens = tweens.write();
+
+                for (key, value) in moved {
+                    if let Some(tween) = tweens.get_mut(&key) {
+                        tween.value = value;
+                    }
+                }
+            }
+
+            let animation_progress = progress
+                .iter()
+                .find(|(key, ..)| longest.as_ref() == Some(key))
+                .map_or(1.0, |(_, progress, ..)| *progress);
+
+            for (key, progress, key_iteration, is_done) in progress {
+                if iterations.insert(key.clone(), key_iteration) != Some(key_iteration) {
+                    lifecycle.iteration_key(&key, key_iteration);
+                }
+
+                lifecycle.update_key(&key, progress);
+
+                if is_done {
+                    lifecycle.complete_key(&key);
+                }
+            }
+
+            if let Some(latest) = iterations.values().copied().max() {
+                if latest != iteration {
+                    iteration = latest;
 
-            let mut index = 0;
-            let mut prev_frame = Instant::now();
+                    lifecycle.iteration(iteration);
+                }
+            }
 
-            loop {
-                // Wait for the event loop to tick
-                ticker.tick().await;
+            lifecycle.update(animation_progress);
 
-                platform.request_animation_frame();
+            if is_finished {
+                is_running.set(false);
 
-                index += prev_frame.elapsed().as_millis();
+                job.write().take();
 
-                // Advance the animations
-                for value in values.values_mut() {
-                    value.write().advance(index as f32);
+                if let Some(completer) = completer.write().take() {
+                    completer.complete(AnimationOutcome::Completed);
                 }
 
-                prev_frame = Instant::now();
+                lifecycle.complete();
+            }
+
+            !is_finished
+        });
+
+        job.write().replace(animation_job);
+
+        completion
+    }
+}
+
+/// Scripting, to write choreographies as async code:
+///
+/// ```ignore
+/// animation.script(|animation| async move {
+///     animation.to("offset", 256.0, Curve::EASE, Duration::from_millis(300)).await;
+///     animation.wait(Duration::from_millis(200)).await;
+///
+///     join!(
+///         animation.to("opacity", 0.0, "standard", "medium2"),
+///         animation.to("offset", 0.0, "emphasized", "long2"),
+///     );
+/// });
+/// ```
+impl Transition {
+    /// Runs `script` in a task of the component, cancelling the previous
+    /// script and the animations it started.
+    pub fn script<F: Future<Output = ()> + 'static>(&self, script: impl FnOnce(Self) -> F) {
+        let mut task = self.script;
+
+        self.cancel_script();
+
+        task.set(Some(spawn(script(*self))));
+    }
+
+    /// Animates `key` from its current value to `value`, interrupting the
+    /// previous [`Transition::to`] of `key`. A played animation stops moving
+    /// `key`, and playing `key` again interrupts this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no tween named `key`.
+    pub fn to<K: AsRef<str>, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+        curve: impl Into<MotionCurve>,
+        duration: impl Into<MotionDuration>,
+    ) -> Completion {
+        let key = key.as_ref().to_owned();
+        let (curve, duration) = MotionTheme::with_current(|theme| {
+            (
+                theme.resolve_curve(&curve.into()),
+                theme.resolve_duration(&duration.into()),
+            )
+        });
+        let clock = AnimationClock::current();
+        let preference = if self.context.peek().essential.contains(&key) {
+            MotionPreference::Full
+        } else {
+            MotionPreference::current()
+        };
+        let mut tweens = self.tweens;
+        let mut tracks = self.tracks;
+
+        // The played animation of `key` would follow the new origin and
+        // destination of the tween, so the step takes over from it
+        tracks.write().remove(&key);
+
+        let mut step = {
+            let mut tweens = tweens.write();
+            let tween = tweens
+                .get_mut(&key)
+                .unwrap_or_else(|| panic!("failed to get tween with {key} name"));
 
-                let is_finished = values.values().all(|value| value.peek().is_done(index));
+            tween.to(value.into());
 
-                if is_finished {
-                    break;
+            Tween::new(tween.origin.clone(), tween.destination.clone())
+                .curve(curve)
+                .duration(duration)
+        };
+        let callbacks = self.callbacks;
+        let mut lifecycle = None;
+        let start = clock.now();
+
+        self.start_step(Some(key.clone()), move || {
+            // Started on the first frame, once the previous step of `key` was
+            // interrupted
+            let lifecycle =
+                lifecycle.get_or_insert_with(|| Lifecycle::start(callbacks, [key.clone()]));
+            let elapsed = clock.now().saturating_sub(start);
+            let time = preference.time(elapsed, step.duration);
+
+            step.value =
+                preference.value_at(time, step.total_duration(), |time| step.value_at(time));
+
+            // Only notify the subscribers when the value moved
+            if tweens
+                .peek()
+                .get(&key)
+                .is_some_and(|tween| tween.value != step.value)
+            {
+                if let Some(tween) = tweens.write().get_mut(&key) {
+                    tween.value = step.value.clone();
                 }
             }
 
-            is_running.set(false);
+            lifecycle.update_key(&key, step.progress(time));
+            lifecycle.update(step.progress(time));
+
+            if !step.is_done(time) {
+                return true;
+            }
+
+            lifecycle.complete();
+
+            false
+        })
+    }
+
+    /// Resolves after `duration`, as measured by the [`AnimationClock`].
+    pub fn wait(&self, duration: impl Into<MotionDuration>) -> Completion {
+        let clock = AnimationClock::current();
+        let end = clock.now()
+            + MotionTheme::with_current(|theme| theme.resolve_duration(&duration.into()));
+
+        self.start_step(None, move || clock.now() < end)
+    }
+
+    fn start_step(
+        &self,
+        key: Option<String>,
+        mut frame: impl FnMut() -> bool + 'static,
+    ) -> Completion {
+        let driver = self.driver;
+        let mut steps = self.steps;
+        let (completer, completion) = Completer::new();
+        let completer = Rc::new(completer);
+
+        if let Some(key) = &key {
+            self.interrupt_steps(|animated| animated == key);
+        }
+
+        let step_completer = completer.clone();
+        let job = driver.register(move || {
+            if frame() {
+                return true;
+            }
+
+            step_completer.complete(AnimationOutcome::Completed);
+
+            false
+        });
+
+        steps.write().insert(job, Step { key, completer });
+
+        completion
+    }
+
+    /// Forgets the steps which ended, and interrupts the ones animating a key
+    /// for which `is_overlapping` returns `true`.
+    fn interrupt_steps(&self, mut is_overlapping: impl FnMut(&str) -> bool) {
+        let driver = self.driver;
+        let mut steps = self.steps;
+        let mut interrupted = Vec::new();
+
+        steps.write().retain(|job, step| {
+            if !driver.is_active(*job) {
+                return false;
+            }
+
+            if step.key.as_deref().is_some_and(&mut is_overlapping) {
+                step.completer.complete(AnimationOutcome::Interrupted);
+
+                interrupted.push(*job);
 
-            task.write().take();
+                return false;
+            }
+
+            true
         });
 
-        // Cancel previous animations
-        task.write().replace(animation_task);
+        // Cancelling sends the cancel events, outside of the signal
+        for job in interrupted {
+            driver.cancel(job);
+        }
+    }
+
+    fn cancel_script(&self) {
+        let mut task = self.script;
+        let mut steps = self.steps;
+
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+
+        let cancelled = std::mem::take(&mut *steps.write());
+
+        // Resolves their completions as cancelled
+        for job in cancelled.keys() {
+            self.driver.cancel(*job);
+        }
     }
 }
 
 pub fn use_transition(run: impl Fn(&mut Context) + 'static) -> Transition {
-    let platform = use_platform();
+    let driver = use_animation_driver();
     let is_running = use_signal(|| false);
     let has_run_yet = use_signal(|| false);
-    let task = use_signal(|| None);
+    let job = use_signal(|| None);
+    let completer = use_signal(|| None);
+    let tracks = use_signal(HashMap::new);
+    let steps = use_signal(IndexMap::new);
+    let script = use_signal(|| None);
+    let mut tweens = use_signal(Default::default);
 
     let context = use_memo(move || {
         let mut context = Context::default();
 
         run(&mut context);
 
+        // The tweens start over from the rebuilt context
+        tweens.set(context.tweens.clone());
+
         context
     });
 
+    let signals = use_key_signals();
+    let callbacks = use_callbacks();
+
+    // The driver outlives the component, its animations mustn't.
+    use_drop(move || {
+        if let Some(job) = *job.peek() {
+            driver.cancel(job);
+        }
+
+        let steps = steps.peek().keys().copied().collect::<Vec<_>>();
+
+        for job in steps {
+            driver.cancel(job);
+        }
+    });
+
     Transition {
         context,
+        tweens,
+        signals,
+        callbacks,
         is_running,
         has_run_yet,
-        platform,
-        task,
+        driver,
+        job,
+        completer,
+        tracks,
+        steps,
+        script,
     }
 }
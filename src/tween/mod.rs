@@ -2,18 +2,33 @@ pub use self::{
     lerp::Lerp,
     value::{Gradient, Value},
 };
-use super::{curves::ICurve, Curve};
+use super::{AnimationDirection, Curve, IterationCount, Repeat};
+use std::time::Duration;
 
 pub mod lerp;
 pub mod value;
 
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "crate::serialization::TweenRepr")
+)]
 pub struct Tween {
     pub origin: Value,
     pub destination: Value,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub value: Value,
-    pub duration: f32,
-    pub delay: f32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::millis"))]
+    pub duration: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::millis"))]
+    pub delay: Duration,
     pub curve: Curve,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Repeat::is_once")
+    )]
+    pub repeat: Repeat,
 }
 
 impl Tween {
@@ -23,22 +38,23 @@ impl Tween {
             origin: origin.clone(),
             destination,
             value: origin,
-            duration: 0.0,
-            delay: 0.0,
+            duration: Duration::ZERO,
+            delay: Duration::ZERO,
             curve: Curve::LINEAR,
+            repeat: Repeat::default(),
         }
     }
 
-    pub fn set_duration(&mut self, millis: u64) {
-        self.duration = millis as f32;
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
     }
 
     pub fn set_curve(&mut self, curve: Curve) {
         self.curve = curve;
     }
 
-    pub fn set_delay(&mut self, millis: u64) {
-        self.delay = millis as f32;
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
     }
 
     pub fn set(&mut self, value: Value) {
@@ -59,35 +75,128 @@ impl Tween {
     }
 
     #[must_use]
-    pub const fn duration(mut self, millis: u64) -> Self {
-        self.duration = millis as f32;
+    pub const fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
 
         self
     }
 
     #[must_use]
-    pub const fn delay(mut self, millis: u64) -> Self {
-        self.delay = millis as f32;
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
 
         self
     }
 
+    /// Plays the tween `count` times, like the CSS
+    /// `animation-iteration-count`.
     #[must_use]
-    pub const fn is_done(&self, time: u128) -> bool {
-        time >= (self.delay + self.duration) as u128
+    pub fn repeat(mut self, count: impl Into<IterationCount>) -> Self {
+        self.repeat.iterations = count.into();
+
+        self
+    }
+
+    /// Plays every other iteration backwards, going back and forth.
+    #[must_use]
+    pub const fn alternate(mut self) -> Self {
+        self.repeat.direction = AnimationDirection::Alternate;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.repeat.direction = direction;
+
+        self
+    }
+
+    /// Waits `delay` between two iterations.
+    #[must_use]
+    pub const fn repeat_delay(mut self, delay: Duration) -> Self {
+        self.repeat.delay = delay;
+
+        self
     }
 
-    pub fn advance(&mut self, time: f32) {
-        if time > self.delay {
-            if matches!(self.curve, Curve::None) {
-                self.value = self.destination.clone();
+    /// Starts at `iteration`, `0.5` starting halfway through the first one.
+    #[must_use]
+    pub const fn iteration_start(mut self, iteration: f32) -> Self {
+        self.repeat.iteration_start = iteration;
+
+        self
+    }
+
+    /// The delay and the duration of all the iterations of the tween, `None`
+    /// if it repeats forever.
+    #[must_use]
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.repeat
+            .total_duration(self.duration)
+            .map(|duration| self.delay.saturating_add(duration))
+    }
+
+    #[must_use]
+    pub fn is_done(&self, elapsed: Duration) -> bool {
+        self.total_duration()
+            .is_some_and(|duration| elapsed >= duration)
+    }
+
+    /// How far the tween is `elapsed` after it started, from `0.0` until its
+    /// delay is over to `1.0`. For a tween repeating forever, it's how far the
+    /// current iteration is.
+    #[must_use]
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self.repeat.total_duration(self.duration) {
+            Some(duration) if duration.is_zero() => {
+                if self.is_done(elapsed) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            _ => self
+                .repeat
+                .progress(elapsed.saturating_sub(self.delay), self.duration),
+        }
+    }
+
+    /// The iteration the tween is at `elapsed` after it started.
+    #[must_use]
+    pub fn iteration(&self, elapsed: Duration) -> u32 {
+        self.repeat
+            .position(elapsed.saturating_sub(self.delay), self.duration)
+            .iteration
+    }
+
+    /// Moves the tween to where it is `elapsed` after it started. Moving it
+    /// back in time retraces the way it came.
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.value = self.value_at(elapsed);
+    }
+
+    /// The value of the tween `elapsed` after it started.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn value_at(&self, elapsed: Duration) -> Value {
+        let progress = self
+            .repeat
+            .position(elapsed.saturating_sub(self.delay), self.duration)
+            .progress;
+
+        if matches!(self.curve, Curve::None) || self.duration.is_zero() {
+            if progress > 0.0 {
+                self.destination.clone()
             } else {
-                self.value = self.origin.lerp(
-                    &self.destination,
-                    self.curve
-                        .transform((time - self.delay).min(self.duration) / self.duration),
-                );
+                self.origin.clone()
             }
+        } else {
+            self.origin.lerp(
+                &self.destination,
+                self.curve
+                    .transform_for_duration(progress as f32, self.duration),
+            )
         }
     }
 }
@@ -0,0 +1,49 @@
+use freya::prelude::*;
+use freya_motion::{use_manual_animation_clock, use_transition, Curve, ManualClock};
+use freya_testing::prelude::*;
+use std::{sync::LazyLock, time::Duration};
+
+static CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+
+fn app() -> Element {
+    use_manual_animation_clock(|| CLOCK.clone());
+
+    let animation = use_transition(|context| {
+        context.add_tween("opacity", 0.0, Curve::LINEAR, Duration::from_millis(500));
+    });
+    let opacity = animation.get::<f32>("opacity");
+
+    use_hook(move || {
+        animation.play([("opacity", 1.0)]);
+    });
+
+    rsx! {
+        label { "{opacity}" }
+    }
+}
+
+#[tokio::test]
+async fn manual_clock_drives_animations() {
+    let mut utils = launch_test(app);
+
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    CLOCK.advance(Duration::from_millis(250));
+
+    // The animation moves on the frame, and the label on the next render
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0.5"));
+
+    CLOCK.advance(Duration::from_millis(250));
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1"));
+}
@@ -0,0 +1,64 @@
+use freya::prelude::*;
+use freya_motion::{
+    use_animation_driver, use_manual_animation_clock, use_segmented_animation, use_transition,
+    AnimationDriver, Curve, ManualClock,
+};
+use freya_testing::prelude::*;
+use std::{cell::Cell, sync::LazyLock, time::Duration};
+
+static CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+
+thread_local! {
+    static DRIVER: Cell<Option<AnimationDriver>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    use_manual_animation_clock(|| CLOCK.clone());
+
+    let driver = use_animation_driver();
+    let segmented = use_segmented_animation(|context| {
+        context.add_tween("offset", 0.0);
+        context.add_segment("offset", 100.0, Curve::LINEAR, Duration::from_millis(1000));
+    });
+    let transition = use_transition(|context| {
+        context.add_tween("opacity", 0.0, Curve::LINEAR, Duration::from_millis(1000));
+    });
+    let offset = segmented.get::<f32>("offset");
+    let opacity = transition.get::<f32>("opacity");
+
+    use_hook(move || {
+        DRIVER.set(Some(driver));
+
+        segmented.play();
+        segmented.pause();
+
+        transition.play([("opacity", 1.0)]);
+        transition.set_rate(0.0);
+    });
+
+    rsx! {
+        label { "{offset} {opacity}" }
+    }
+}
+
+#[tokio::test]
+async fn paused_animations_do_not_request_frames() {
+    let mut utils = launch_test(app);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let driver = DRIVER.get().unwrap();
+    let label = utils.root().get(0);
+
+    assert_eq!(driver.active_count(), 0);
+    assert!(!driver.is_running());
+
+    CLOCK.advance(Duration::from_millis(500));
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert!(!driver.is_running());
+    assert_eq!(label.get(0).text(), Some("0 0"));
+}
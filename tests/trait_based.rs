@@ -0,0 +1,69 @@
+use freya::prelude::*;
+use freya_motion::{
+    use_manual_animation_clock, use_trait_animation, AnimationEvent, KeyFrameAnimation, ManualClock,
+};
+use freya_testing::prelude::*;
+use std::{cell::RefCell, sync::LazyLock, time::Duration};
+
+static CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+
+thread_local! {
+    static HANDLE_UPDATES: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
+    static KEY_UPDATES: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
+}
+
+fn slide(duration: u64) -> KeyFrameAnimation {
+    KeyFrameAnimation::default()
+        .keyframe_at(0.0, 0.0, None)
+        .keyframe_at(1.0, 100.0, None)
+        .duration(Duration::from_millis(duration))
+}
+
+fn app() -> Element {
+    use_manual_animation_clock(|| CLOCK.clone());
+
+    let animation = use_trait_animation(|context| {
+        context.add_tween("offset", 0.0);
+        context.add_tween("opacity", 0.0);
+    });
+    let offset = animation.get::<f32>("offset");
+
+    animation.on_event(|event| {
+        if let AnimationEvent::Update(progress) = event {
+            HANDLE_UPDATES.with_borrow_mut(|updates| updates.push(progress));
+        }
+    });
+    animation.on_key_event("offset", |event| {
+        if let AnimationEvent::Update(progress) = event {
+            KEY_UPDATES.with_borrow_mut(|updates| updates.push(progress));
+        }
+    });
+
+    use_hook(move || {
+        animation.play("offset", slide(100));
+        animation.play("opacity", slide(200));
+    });
+
+    rsx! {
+        label { "{offset}" }
+    }
+}
+
+#[tokio::test]
+async fn the_handle_updates_once_per_frame() {
+    let mut utils = launch_test(app);
+
+    utils.wait_for_update().await;
+
+    CLOCK.advance(Duration::from_millis(50));
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let handle = HANDLE_UPDATES.with_borrow(Clone::clone);
+    let key = KEY_UPDATES.with_borrow(Clone::clone);
+
+    // Both keys moved on every frame, the handle following the slowest one
+    assert_eq!(handle.len(), key.len());
+    assert_eq!(handle.last(), Some(&0.25));
+}
@@ -0,0 +1,107 @@
+use freya::prelude::*;
+use freya_motion::{
+    use_manual_animation_clock, use_transition, AnimationEvent, Curve, ManualClock,
+};
+use freya_testing::prelude::*;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::Duration,
+};
+
+static CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+static OVERLAP_CLOCK: LazyLock<ManualClock> = LazyLock::new(ManualClock::new);
+static MOVED: AtomicBool = AtomicBool::new(false);
+
+fn app() -> Element {
+    use_manual_animation_clock(|| CLOCK.clone());
+
+    let animation = use_transition(|context| {
+        context.add_tween("offset", 0.0, Curve::LINEAR, Duration::from_millis(100));
+        context.add_tween("opacity", 0.0, Curve::LINEAR, Duration::from_millis(100));
+    });
+    let offset = animation.get::<f32>("offset");
+
+    // Sends `offset` back once `opacity` is shown, from within the frame
+    animation.on_key_event("opacity", move |event| {
+        if event == AnimationEvent::Complete {
+            animation.to("offset", 0.0, Curve::LINEAR, Duration::from_millis(100));
+        }
+    });
+
+    use_hook(move || {
+        animation.to("offset", 100.0, Curve::LINEAR, Duration::from_millis(1000));
+        animation.to("opacity", 1.0, Curve::LINEAR, Duration::from_millis(100));
+    });
+
+    rsx! {
+        label { "{offset}" }
+    }
+}
+
+#[tokio::test]
+async fn to_from_a_completion_callback_interrupts_the_step_of_its_key() {
+    let mut utils = launch_test(app);
+
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+
+    for _ in 0..3 {
+        CLOCK.advance(Duration::from_millis(100));
+
+        // The animation moves on the frame, and the label on the next render
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    // The first step of `offset` would have moved it to 30 by now
+    assert_eq!(label.get(0).text(), Some("0"));
+}
+
+fn overlapping_app() -> Element {
+    use_manual_animation_clock(|| OVERLAP_CLOCK.clone());
+
+    let animation = use_transition(|context| {
+        context.add_tween("offset", 0.0, Curve::LINEAR, Duration::from_millis(1000));
+    });
+    let offset = animation.get::<f32>("offset");
+
+    // Sends `offset` back halfway through the played animation
+    animation.on_key_event("offset", move |event| {
+        if matches!(event, AnimationEvent::Update(progress) if progress >= 0.5)
+            && !MOVED.swap(true, Ordering::Relaxed)
+        {
+            animation.to("offset", 0.0, Curve::LINEAR, Duration::from_millis(100));
+        }
+    });
+
+    use_hook(move || {
+        animation.play([("offset", 100.0)]);
+    });
+
+    rsx! {
+        label { "{offset}" }
+    }
+}
+
+#[tokio::test]
+async fn to_takes_over_the_played_animation_of_its_key() {
+    let mut utils = launch_test(overlapping_app);
+
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+
+    for _ in 0..7 {
+        OVERLAP_CLOCK.advance(Duration::from_millis(100));
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    // The played animation would have moved `offset` back to 15
+    assert_eq!(label.get(0).text(), Some("0"));
+}